@@ -0,0 +1,54 @@
+"""Measure the time spent collecting the test fixtures with pooled parsers and a shared parser.
+
+Usage:
+    python scripts/benchmark_collection.py [--repeat 100]
+"""
+
+import argparse
+import time
+from contextlib import contextmanager
+from pathlib import Path
+
+from maxx.collection import PathsCollection
+from maxx.treesitter import PARSER, ParserPool
+
+FIXTURES = Path(__file__).parent.parent / "tests" / "files"
+
+
+def collect(repeat: int) -> float:
+    """Collect and parse every file in the fixtures directory, returning the elapsed time."""
+    start = time.perf_counter()
+    for _ in range(repeat):
+        collection = PathsCollection([FIXTURES], recursive=True)
+        for alias in collection.members.values():
+            alias.target  # Force parsing of every file.
+    return time.perf_counter() - start
+
+
+@contextmanager
+def _shared_parser(self: ParserPool):
+    """Parse every file with the shared module parser, as done before parsers were pooled."""
+    yield PARSER
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--repeat", type=int, default=100, help="Number of collections.")
+    args = parser.parse_args()
+
+    pooled = collect(args.repeat)
+
+    borrow = ParserPool.parser
+    ParserPool.parser = _shared_parser
+    try:
+        shared = collect(args.repeat)
+    finally:
+        ParserPool.parser = borrow
+
+    print(f"collections:    {args.repeat}")
+    print(f"shared parser:  {shared:.2f} s")
+    print(f"pooled parsers: {pooled:.2f} s ({shared / pooled:.2f}x)")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,82 @@
+"""Measure the memory used by collecting a large synthetic MATLAB code base.
+
+Usage:
+    python scripts/benchmark_memory.py [--files 10000]
+"""
+
+import argparse
+import resource
+import sys
+import tempfile
+import time
+import tracemalloc
+from pathlib import Path
+
+from maxx.collection import PathsCollection
+
+FUNCTION = """function out = {name}(x, y, options)
+% {name} Synthetic benchmark function.
+    arguments
+        x (1,1) double {{mustBePositive}}
+        y (1,:) double = 1
+        options.Mode (1,1) string = "default"
+    end
+    out = x + y;
+end
+"""
+
+CLASS = """classdef {name} < handle
+% {name} Synthetic benchmark class.
+    properties (Access = public)
+        Value (1,1) double = 0
+        Name (1,1) string = ""
+    end
+    methods
+        function obj = {name}(value)
+            obj.Value = value;
+        end
+    end
+end
+"""
+
+
+def write_corpus(root: Path, files: int) -> None:
+    """Write a corpus of functions and classes spread over namespaces."""
+    for index in range(files):
+        folder = root / f"+ns{index % 50}" / f"+sub{index % 7}"
+        folder.mkdir(parents=True, exist_ok=True)
+        template = CLASS if index % 4 == 0 else FUNCTION
+        name = f"{'Class' if index % 4 == 0 else 'func'}{index}"
+        (folder / f"{name}.m").write_text(template.format(name=name))
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--files", type=int, default=10_000, help="Number of files to generate.")
+    args = parser.parse_args()
+
+    with tempfile.TemporaryDirectory() as directory:
+        root = Path(directory)
+        write_corpus(root, args.files)
+
+        tracemalloc.start()
+        start = time.perf_counter()
+        collection = PathsCollection([root], recursive=True)
+        for alias in collection.members.values():
+            alias.target  # Force parsing of every file.
+        elapsed = time.perf_counter() - start
+        _, peak = tracemalloc.get_traced_memory()
+        tracemalloc.stop()
+
+    # ru_maxrss is reported in kilobytes on Linux and in bytes on macOS.
+    rss = resource.getrusage(resource.RUSAGE_SELF).ru_maxrss
+    rss_mb = rss / 1024**2 if sys.platform == "darwin" else rss / 1024
+    print(f"files:            {args.files}")
+    print(f"members:          {len(collection.members)}")
+    print(f"time:             {elapsed:.1f} s")
+    print(f"peak traced heap: {peak / 1024**2:.1f} MB")
+    print(f"max RSS:          {rss_mb:.1f} MB")
+
+
+if __name__ == "__main__":
+    main()
@@ -7,17 +7,41 @@ import logging
 from griffe._internal.docstrings import google, numpy
 from griffe._internal.enumerations import DocstringSectionKind
 
-from maxx import collection, config, enums, livescript, objects, treesitter
+from maxx import (
+    collection,
+    config,
+    contents,
+    documentation,
+    enums,
+    examples,
+    livescript,
+    objects,
+    resolve,
+    telemetry,
+    treesitter,
+)
+from maxx.collection import PathsCollection, parse_directory, parse_files
 from maxx.config import ParserConfig
+from maxx.expressions import load_builtins, set_builtins
 
 __all__: list = [
     "collection",
     "config",
+    "contents",
+    "documentation",
     "enums",
+    "examples",
     "livescript",
     "objects",
+    "resolve",
+    "telemetry",
     "treesitter",
     "ParserConfig",
+    "PathsCollection",
+    "parse_directory",
+    "parse_files",
+    "load_builtins",
+    "set_builtins",
 ]
 
 
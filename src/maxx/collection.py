@@ -2,14 +2,22 @@
 
 from __future__ import annotations
 
+import hashlib
+import re
+import sys
+import time
 from collections import defaultdict, deque
+from dataclasses import replace
 from pathlib import Path
-from typing import Any, ItemsView, KeysView, Sequence, TypeVar, ValuesView, cast
+from typing import Any, ItemsView, Iterator, KeysView, Sequence, TypeVar, ValuesView, cast
 
 from griffe._internal.models import Object as GriffeObject
 from loguru import logger
 
 from maxx.config import ParserConfig
+from maxx.enums import CacheStrategy, Kind, ParseIssueKind, PathNormalization
+from maxx.exceptions import AmbiguousNameError, FilePathError
+from maxx.mixins import PickleMixin
 from maxx.objects import (
     Alias,
     Class,
@@ -20,7 +28,8 @@ from maxx.objects import (
     Namespace,
     Object,
 )
-from maxx.treesitter import FileParser
+from maxx.telemetry import CollectionStats, FileStats
+from maxx.treesitter import FileParser, ParseIssue, ParserPool
 
 MFILE_SUFFIX = ".m"
 MLX_SUFFIX = ".mlx"
@@ -29,11 +38,158 @@ NAMESPACE_PREFIX = "+"
 FOLDER_PREFIXES = (CLASSFOLDER_PREFIX, NAMESPACE_PREFIX)
 PRIVATE_FOLDER = "private"
 CONTENTS_FILE = "Contents.m"
+IGNORE_FILES = (".gitignore", ".ignore", ".maxxignore")
+PATHDEF_ENTRY = re.compile(r"(matlabroot\s*,\s*)?'((?:[^']|'')*)'")
 
 
 PathType = TypeVar("PathType", bound=Object)
 
-__all__ = ["LinesCollection", "PathsCollection"]
+__all__ = ["LinesCollection", "PathsCollection", "parse_directory", "parse_files"]
+
+
+def _git_root(path: Path) -> Path | None:
+    """
+    Find the root of the git repository containing a path.
+
+    Args:
+        path: The path to start searching from.
+
+    Returns:
+        The repository root, or None if the path is not inside a git repository.
+    """
+    for directory in (path, *path.parents):
+        if (directory / ".git").exists():
+            return directory
+    return None
+
+
+def _content_hash(path: Path) -> str:
+    """
+    Hash the content of a file.
+
+    Args:
+        path: The file to hash.
+
+    Returns:
+        The hexadecimal BLAKE2 digest of the content.
+    """
+    return hashlib.blake2b(path.read_bytes(), digest_size=32).hexdigest()
+
+
+def _translate_ignore_pattern(pattern: str) -> str:
+    """
+    Translate a gitignore glob pattern into a regular expression.
+
+    Args:
+        pattern: The glob pattern, without leading or trailing slashes.
+
+    Returns:
+        The regular expression matching the pattern.
+    """
+    regex = ""
+    index = 0
+    while index < len(pattern):
+        if pattern.startswith("**/", index):
+            regex += "(?:.*/)?"
+            index += 3
+        elif pattern.startswith("**", index):
+            regex += ".*"
+            index += 2
+        elif pattern[index] == "*":
+            regex += "[^/]*"
+            index += 1
+        elif pattern[index] == "?":
+            regex += "[^/]"
+            index += 1
+        elif pattern[index] == "[" and "]" in pattern[index + 2 :]:
+            end = pattern.index("]", index + 2)
+            content = pattern[index + 1 : end]
+            if content.startswith("!"):
+                content = "^" + content[1:]
+            regex += f"[{content}]"
+            index = end + 1
+        else:
+            if pattern[index] == "\\" and index + 1 < len(pattern):
+                index += 1
+            regex += re.escape(pattern[index])
+            index += 1
+    return regex
+
+
+class _IgnorePattern:
+    """
+    A single pattern of an ignore file, following the gitignore syntax.
+    """
+
+    def __init__(self, base: Path, pattern: str):
+        self.base = base.absolute()
+        self.negated = pattern.startswith("!")
+        if self.negated:
+            pattern = pattern[1:]
+        self.directory_only = pattern.endswith("/")
+        pattern = pattern.rstrip("/")
+        # Patterns containing a slash are relative to the directory of the ignore file,
+        # other patterns match at any depth.
+        anchored = "/" in pattern
+        regex = _translate_ignore_pattern(pattern.lstrip("/"))
+        self._regex = re.compile(regex if anchored else f"(?:.*/)?{regex}")
+
+    def matches(self, path: Path, is_dir: bool) -> bool:
+        if self.directory_only and not is_dir:
+            return False
+        try:
+            relative = path.absolute().relative_to(self.base)
+        except ValueError:
+            return False
+        return self._regex.fullmatch(relative.as_posix()) is not None
+
+
+class _IgnoreRules:
+    """
+    A class to collect the ignore files of directories and match paths against them.
+    """
+
+    def __init__(self) -> None:
+        self._patterns: list[_IgnorePattern] = []
+        self._loaded: set[Path] = set()
+
+    @classmethod
+    def for_path(cls, path: Path) -> _IgnoreRules:
+        """Create the rules applying to a path, including those of its parents in the repository."""
+        rules = cls()
+        path = path.absolute()
+        root = _git_root(path)
+        if root is not None:
+            for directory in reversed((path, *path.parents)):
+                if directory == root or root in directory.parents:
+                    rules.load(directory)
+        else:
+            rules.load(path)
+        return rules
+
+    def load(self, directory: Path) -> None:
+        """Load the patterns of the ignore files in a directory."""
+        directory = directory.absolute()
+        if directory in self._loaded:
+            return
+        self._loaded.add(directory)
+        for name in IGNORE_FILES:
+            ignore_file = directory / name
+            if not ignore_file.is_file():
+                continue
+            for line in ignore_file.read_text(errors="replace").splitlines():
+                line = line.rstrip()
+                if line and not line.startswith("#"):
+                    self._patterns.append(_IgnorePattern(directory, line))
+
+    def is_ignored(self, path: Path) -> bool:
+        """Whether a path is ignored, where the last matching pattern takes precedence."""
+        ignored = False
+        is_dir = path.is_dir()
+        for pattern in self._patterns:
+            if pattern.matches(path, is_dir):
+                ignored = not pattern.negated
+        return ignored
 
 
 class _PathGlobber:
@@ -41,14 +197,43 @@ class _PathGlobber:
     A class to recursively glob paths as MATLAB would do it.
     """
 
-    def __init__(self, path: Path, recursive: bool = False, parse_live_scripts: bool = False):
+    def __init__(
+        self,
+        path: Path,
+        recursive: bool = False,
+        parse_live_scripts: bool = False,
+        ignore: _IgnoreRules | None = None,
+        max_file_size: int | None = None,
+        max_depth: int | None = None,
+    ):
         self._idx = 0
         self._paths: list[Path] = []
         self._parse_live_scripts = parse_live_scripts
-        self._glob(path, recursive)
-
-    def _glob(self, path: Path, recursive: bool = False):
+        self._ignore = ignore
+        self._max_file_size = max_file_size
+        self.ignored: list[Path] = []
+        self.oversized: dict[Path, int] = {}
+        if max_depth is None:
+            max_depth = None if recursive else 1
+        self._glob(path, max_depth)
+
+    def _add_file(self, member: Path) -> None:
+        if self._max_file_size is not None:
+            size = member.stat().st_size
+            if size > self._max_file_size:
+                self.oversized[member] = size
+                return
+        self._paths.append(member)
+
+    def _glob(self, path: Path, max_depth: int | None = 1):
+        # The depth includes the globbed path itself, and is unlimited if None.
+        recursive = max_depth is None or max_depth > 1
+        if self._ignore is not None:
+            self._ignore.load(path)
         for member in path.iterdir():
+            if self._ignore is not None and self._ignore.is_ignored(member):
+                self.ignored.append(member)
+                continue
             if (
                 member.is_dir()
                 and recursive
@@ -63,16 +248,16 @@ class _PathGlobber:
                 )
                 if has_mfiles:
                     self._paths.append(member)
-                self._glob(member, recursive=True)
+                self._glob(member, None if max_depth is None else max_depth - 1)
             elif member.is_dir() and member.stem[0] in FOLDER_PREFIXES:
                 self._paths.append(member)
                 self._glob(member)
             elif (
                 member.is_file() and member.suffix == MFILE_SUFFIX and member.name != CONTENTS_FILE
             ):
-                self._paths.append(member)
+                self._add_file(member)
             elif member.is_file() and member.suffix == MLX_SUFFIX and self._parse_live_scripts:
-                self._paths.append(member)
+                self._add_file(member)
 
     def max_stem_length(self) -> int:
         return max(len(path.stem) for path in self._paths)
@@ -176,32 +361,67 @@ class _PathResolver:
                 ValueError("Parent must be a namespace")
         return self._object
 
-    def _collect_path(self, path: Path, **kwargs: Any) -> Object:
+    def _collect_path(self, path: Path, **kwargs: Any) -> Object | None:
         if path.suffix == MLX_SUFFIX:
             from maxx.livescript import LiveScriptParser
 
+            self._paths_collection._record_fingerprint(path)
             parser = LiveScriptParser(path, paths_collection=self._paths_collection)
             return parser.parse()
-        file = FileParser(path, paths_collection=self._paths_collection)
-        object = file.parse(
-            config=self._paths_collection._parser_config,
-            paths_collection=self._paths_collection,
-            **kwargs,
-        )
-        self._paths_collection.lines_collection[path] = file.content.split("\n")
+
+        paths_collection = self._paths_collection
+        paths_collection._record_fingerprint(path)
+        tolerant = paths_collection._tolerant
+        start = time.perf_counter()
+        with paths_collection._parsers.parser() as parser:
+            try:
+                file = FileParser.with_parser(parser, path, paths_collection=paths_collection)
+            except OSError as ex:
+                if not tolerant:
+                    raise
+                paths_collection._record_issues(path, [ParseIssue(ParseIssueKind.io, str(ex))])
+                return None
+
+            if tolerant:
+                result = file.parse_tolerant(
+                    config=paths_collection._parser_config,
+                    paths_collection=paths_collection,
+                    **kwargs,
+                )
+                paths_collection._record_issues(path, result.errors)
+                object = result.object
+            else:
+                object = file.parse(
+                    config=paths_collection._parser_config,
+                    paths_collection=paths_collection,
+                    **kwargs,
+                )
+        parse_time = time.perf_counter() - start
+        size = path.stat().st_size
+        logger.trace(f"Parsed {path} ({size} bytes) in {parse_time:.3f} s")
+        paths_collection._stats.files.append(FileStats(path, size, parse_time))
+
+        try:
+            paths_collection.lines_collection[path] = file.content.split("\n")
+        except UnicodeDecodeError:
+            if not tolerant:
+                raise
         return object
 
     def _collect_directory(self, path: Path, object: PathType, set_parent: bool = False) -> None:
         for item in path.iterdir():
+            if self._paths_collection._is_excluded(item):
+                continue
             if item.is_file() and item.suffix == MFILE_SUFFIX:
                 if item.name == CONTENTS_FILE:
                     contentsfile = self._collect_path(item)
-                    object.docstring = contentsfile.docstring
+                    if contentsfile is not None:
+                        object.docstring = contentsfile.docstring
                 else:
                     if item not in self._paths_collection._objects:
                         logger.warning(f"Path not found in collection (file): {item}")
                         raise KeyError(f"Path not found in collection: {item}")
-                    subobject = self._paths_collection._objects[item].target
+                    subobject = self._paths_collection._resolve(item)
                     if subobject is not None:
                         object.members[subobject.name] = subobject
                         if set_parent:
@@ -236,15 +456,18 @@ class _PathResolver:
             classfile = classfile.target
 
         for member in path.iterdir():
+            if self._paths_collection._is_excluded(member):
+                continue
             if member.is_file() and member.suffix == MFILE_SUFFIX and member != classfile:
                 if member.name == CONTENTS_FILE and object.docstring is None:
                     contentsfile = self._collect_path(member)
-                    object.docstring = contentsfile.docstring
+                    if contentsfile is not None:
+                        object.docstring = contentsfile.docstring
                 else:
                     if member not in self._paths_collection._objects:
                         logger.warning(f"Path not found in collection (class member): {member}")
                         raise KeyError(f"Path not found in collection: {member}")
-                    method = self._paths_collection._objects[member].target
+                    method = self._paths_collection._resolve(member)
                     if method is not None and isinstance(method, Function):
                         method.parent = classfile
                         object.members[method.name] = method
@@ -343,7 +566,7 @@ class LinesCollection:
         return self._data.items()
 
 
-class PathsCollection:
+class PathsCollection(PickleMixin):
     """
     PathsCollection is a class that manages a collection of MATLAB paths and their corresponding objects.
 
@@ -354,6 +577,7 @@ class PathsCollection:
     Args:
         matlab_path (Sequence[str | Path]): A list of strings or Path objects representing the MATLAB paths.
         recursive (bool, optional): If True, recursively adds all subdirectories of the given paths to the search path. Defaults to False.
+        max_depth (int | None, optional): The number of directory levels to add, overriding `recursive`. Defaults to None.
         working_directory (Path | None, optional): The path to the configuration file. Defaults to None.
 
     Methods:
@@ -366,12 +590,42 @@ class PathsCollection:
         update_object(object: Object, config: Mapping) -> Object:
             Updates the given object object with the provided configuration.
 
-        addpath(path: str | Path, to_end: bool = False, recursive: bool = False) -> list[Path]:
+        addpath(path: str | Path, to_end: bool = False, recursive: bool = False, max_depth: int | None = None) -> list[Path]:
             Adds a path to the search path.
 
         rmpath(path: str | Path, recursive: bool = False) -> list[Path]:
             Removes a path from the search path and updates the namespace and database accordingly.
 
+        remove(identifier: str) -> Object | None:
+            Removes the object an identifier resolves to from the collection.
+
+        remove_path(path: str | Path) -> list[str]:
+            Removes all objects defined in a file or directory from the collection.
+
+        refresh() -> list[Path]:
+            Parses the files that changed or were deleted since they were parsed again.
+
+        reload_file(path: str | Path) -> Object | None:
+            Parses a single file again, returning the object it defined before.
+
+        source_path(identifier: str) -> Path | None:
+            Returns the source file in which the identifier is defined.
+
+        precedence_order(identifier: str) -> list[Path]:
+            Returns all source files defining the identifier, in search path order.
+
+        walk() -> Iterator[tuple[str, Object]]:
+            Iterates over all objects in the collection and their members.
+
+        ancestors(identifier: str) -> Iterator[Namespace | Folder]:
+            Iterates over the namespaces and folders containing an object, innermost first.
+
+        resolve_qualified(qualified_name: str) -> Object | None:
+            Resolves a dotted name by walking the namespaces and classes it consists of.
+
+        load_pathdef(pathdef: str | Path, matlabroot: str | Path | None = None) -> dict[str, Path]:
+            Reads the directories listed in a pathdef.m file as path aliases.
+
     """
 
     is_collection = True
@@ -385,6 +639,13 @@ class PathsCollection:
         parse_live_scripts: bool = False,
         _local: bool = False,
         parser_config: ParserConfig | None = None,
+        respect_gitignore: bool | None = None,
+        max_file_size: int | None = None,
+        tolerant: bool = True,
+        aliases: dict[str, str | Path] | None = None,
+        path_normalization: PathNormalization | None = None,
+        cache_strategy: CacheStrategy = CacheStrategy.mtime,
+        max_depth: int | None = None,
     ):
         """
         Initialize an instance of PathsCollection.
@@ -400,6 +661,35 @@ class PathsCollection:
                 and may be large binary files.
             parser_config (ParserConfig | None): Configuration for parsing MATLAB files.
                 If None, default configuration is used.
+            respect_gitignore (bool | None): Whether files matched by ``.gitignore``, ``.ignore``
+                and ``.maxxignore`` files are left out of the collection. Paths that are added
+                explicitly are never ignored. If None, ignore files are respected for paths
+                inside a git repository.
+            max_file_size (int | None): The size in bytes above which files are skipped instead
+                of parsed, e.g. for large generated lookup tables. Skipped files are logged and
+                listed in [`skipped`][maxx.collection.PathsCollection.skipped]. If None, files
+                of any size are collected.
+            tolerant (bool): If True, files that cannot be read or parsed do not raise an error
+                when they are resolved. Their problems are logged and listed in
+                [`issues`][maxx.collection.PathsCollection.issues], together with the syntax
+                errors that were recovered from in parsed files.
+            aliases (dict[str, str | Path] | None): A mapping from logical names to directories,
+                e.g. as read with [`load_pathdef`][maxx.collection.PathsCollection.load_pathdef].
+                The directories are added to the end of the search path, and identifiers that
+                start with a logical name, such as ``mylib.func``, are resolved from the files
+                under the mapped directory.
+            path_normalization (PathNormalization | None): How added paths are normalized, so
+                that different spellings of the same directory, such as ``C:\\Proj`` and
+                ``c:\\proj\\..\\proj``, are collected once. With
+                ``PathNormalization.case_insensitive``, identifiers that are not found are also
+                looked up case-insensitively. If None, paths are looked up case-insensitively on
+                Windows and used as given on other platforms.
+            cache_strategy (CacheStrategy): How [`refresh`][maxx.collection.PathsCollection.refresh]
+                detects the files that changed since they were parsed.
+            max_depth (int | None): The number of directory levels to add from each path, where 1
+                adds only the path itself, as with ``recursive=False``, and 2 also adds its
+                subdirectories but not theirs. Namespace and class folders are always collected
+                and do not count as a level. If None, the depth follows ``recursive``.
         Raises:
             TypeError: If any element in matlab_path is not a string or Path object.
         """
@@ -430,14 +720,54 @@ class PathsCollection:
             parser_config if parser_config is not None else ParserConfig()
         )
         # Configuration for parsing MATLAB files.
+        self._respect_gitignore: bool | None = respect_gitignore
+        # Whether ignore files are respected, or None to respect them inside git repositories.
+        self._ignored: set[Path] = set()
+        # The paths left out of the collection because of ignore files.
+        self._max_file_size: int | None = max_file_size
+        # The size in bytes above which files are skipped, or None for no limit.
+        self._skipped: dict[Path, str] = {}
+        # The files left out of the collection, with the reason they were skipped.
+        self._removed: set[Path] = set()
+        # The collected paths that were removed, left out when their directory is collected again.
+        self._selected: set[Path] | None = None
+        # The only files that are collected, with the directories containing them, or None for all.
+        self._tolerant: bool = tolerant
+        # Whether problems in files are recorded instead of raised.
+        self._issues: dict[Path, list[ParseIssue]] = {}
+        # The problems encountered while reading or parsing each file.
+        self._stats: CollectionStats = CollectionStats()
+        # The time spent walking the added paths and parsing files.
+        self._parsers: ParserPool = ParserPool()
+        # The tree-sitter parsers that are reused to parse the files in the collection.
+        self._path_normalization: PathNormalization = (
+            path_normalization if path_normalization is not None else PathNormalization.default()
+        )
+        # How added paths are normalized, and whether identifiers are looked up case-insensitively.
+        self._casefolded: dict[str, list[str]] = {}
+        # The identifiers in the mapping by their case-folded form, for case-insensitive lookups.
+        self._cache_strategy: CacheStrategy = cache_strategy
+        # How changed files are detected when the collection is refreshed.
+        self._fingerprints: dict[Path, tuple[int | None, str | None]] = {}
+        # The modification time and content hash of each parsed file, when it was parsed.
+        self._aliases: dict[str, Path] = {
+            sys.intern(name): self._normalize(Path(path)) for name, path in (aliases or {}).items()
+        }
+        # The logical names that resolve identifiers from the files under a directory.
         self.lines_collection = LinesCollection()
 
         for path in matlab_path:
-            self.addpath(Path(path), to_end=True, recursive=recursive)
+            self.addpath(Path(path), to_end=True, recursive=recursive, max_depth=max_depth)
+        for path in self._aliases.values():
+            if path not in self._path:
+                self.addpath(path, to_end=True, recursive=recursive, max_depth=max_depth)
 
     @staticmethod
     def as_local_collection(
-        path: Path, parser_config: ParserConfig | None = None
+        path: Path,
+        parser_config: ParserConfig | None = None,
+        tolerant: bool = True,
+        path_normalization: PathNormalization | None = None,
     ) -> PathsCollection:
         """
         Create a local PathsCollection for a given path.
@@ -446,6 +776,9 @@ class PathsCollection:
             path (Path): The path for which to create the local collection.
             parser_config (ParserConfig | None): Configuration for parsing MATLAB files.
                 If None, default configuration is used.
+            tolerant (bool): Whether problems in files are recorded instead of raised.
+            path_normalization (PathNormalization | None): How paths are normalized. If None,
+                the default of the platform is used.
 
         Returns:
             PathsCollection: A new PathsCollection instance for the given path.
@@ -458,14 +791,236 @@ class PathsCollection:
             working_directory=path,
             _local=True,
             parser_config=parser_config,
+            tolerant=tolerant,
+            path_normalization=path_normalization,
         )
         collection._path.appendleft(path)
         return collection
 
+    @staticmethod
+    def load_pathdef(pathdef: str | Path, matlabroot: str | Path | None = None) -> dict[str, Path]:
+        """
+        Read the directories listed in a ``pathdef.m`` file as path aliases.
+
+        Each directory is mapped from its name, e.g. ``C:\\work\\mylib`` from ``mylib``. If
+        several directories have the same name, the first one takes precedence, as it does on the
+        MATLAB path.
+
+        Args:
+            pathdef (str | Path): The ``pathdef.m`` file to read.
+            matlabroot (str | Path | None): The MATLAB installation directory, which is
+                prepended to the entries that are relative to ``matlabroot``. If None, these
+                entries are left out.
+
+        Returns:
+            dict[str, Path]: The mapping from directory names to directories, in path order.
+        """
+        text = Path(pathdef).read_text(encoding="utf-8", errors="replace")
+        if "%%% BEGIN ENTRIES %%%" in text:
+            text = text.split("%%% BEGIN ENTRIES %%%", 1)[1].split("%%% END ENTRIES %%%", 1)[0]
+
+        aliases: dict[str, Path] = {}
+        for line in text.splitlines():
+            if line.lstrip().startswith("%"):
+                continue
+            for relative, value in PATHDEF_ENTRY.findall(line):
+                if relative and matlabroot is None:
+                    continue
+                for entry in value.replace("''", "'").split(";"):
+                    entry = entry.rstrip(":").strip()
+                    if not entry:
+                        continue
+                    path = Path(f"{matlabroot}{entry}") if relative else Path(entry)
+                    aliases.setdefault(sys.intern(path.name), path)
+        return aliases
+
     @property
     def members(self) -> dict[str, Any]:
         return {identifier: self._objects[paths[0]] for identifier, paths in self._mapping.items()}
 
+    def walk(self) -> Iterator[tuple[str, Object]]:
+        """
+        Iterate over all objects in the collection and their members, depth-first.
+
+        Each object is visited once, even if it is reachable from several members, such as a
+        function that is both a member of the collection and of its namespace.
+
+        Yields:
+            tuple[str, Object]: The dotted path of each object, and the object.
+        """
+        seen: set[int] = set()
+        for alias in self.members.values():
+            target = alias.target
+            if target is None:
+                continue
+            for object in target.walk():
+                if id(object) not in seen:
+                    seen.add(id(object))
+                    yield object.path, object
+
+    def ancestors(self, identifier: str) -> Iterator[Namespace | Folder]:
+        """
+        Iterate over the namespaces and folders containing an object, e.g. for breadcrumbs.
+
+        Args:
+            identifier (str): The identifier of the object, e.g. ``mypackage.sub.MyClass``.
+
+        Yields:
+            Namespace | Folder: The containing namespaces and folders, from the innermost to the
+                outermost one. Class folders are skipped, and added paths themselves have no
+                object.
+        """
+        object = self[identifier]
+        if object is None:
+            return
+        try:
+            filepath = object.filepath
+        except FilePathError:
+            return
+        for directory in filepath.parents:
+            if directory not in self._objects:
+                break
+            ancestor = self._objects[directory].target
+            if isinstance(ancestor, (Namespace, Folder)) and not isinstance(ancestor, ClassFolder):
+                yield ancestor
+
+    def tests(self) -> dict[str, Class | ClassFolder | Function]:
+        """
+        Get the tests in the collection, e.g. to leave them out of generated documentation.
+
+        Tests are classes deriving from `matlab.unittest.TestCase`, and function-based tests
+        whose main function returns `functiontests(localfunctions)`, including those in
+        namespaces.
+
+        Returns:
+            dict[str, Class | ClassFolder | Function]: The tests by dotted path.
+        """
+        tests: dict[str, Class | ClassFolder | Function] = {}
+        for identifier, object in self.walk():
+            if isinstance(object, (Class, ClassFolder)) and object.is_test_class(self):
+                tests[identifier] = object
+            elif isinstance(object, Function) and object.is_function_test:
+                tests[identifier] = object
+        return tests
+
+    @property
+    def skipped(self) -> dict[Path, str]:
+        """The files left out of the collection because they are too large, with the reason."""
+        return dict(self._skipped)
+
+    @property
+    def stats(self) -> CollectionStats:
+        """The time spent walking the paths and parsing the resolved files so far, as a snapshot."""
+        return replace(
+            self._stats,
+            files=list(self._stats.files),
+            files_scanned=sum(
+                path.suffix in (MFILE_SUFFIX, MLX_SUFFIX) for path in self._objects
+            ),
+            files_skipped=len(self._skipped),
+            parse_errors=[
+                (path, str(issue)) for path, issues in self._issues.items() for issue in issues
+            ],
+        )
+
+    @property
+    def issues(self) -> dict[Path, list[ParseIssue]]:
+        """The problems encountered while reading or parsing the resolved files, per file."""
+        return {path: list(issues) for path, issues in self._issues.items()}
+
+    def statistics(self) -> CollectionStats:
+        """
+        Summarize the contents of the collection, e.g. to diagnose why it is smaller than expected.
+
+        All files are resolved, so that the objects and problems of every file are included.
+
+        Returns:
+            CollectionStats: The [`stats`][maxx.collection.PathsCollection.stats] of the
+                collection, with the number of objects of each [`Kind`][maxx.enums.Kind].
+        """
+        by_kind: dict[Kind, int] = defaultdict(int)
+        for _, object in self.walk():
+            by_kind[object.kind] += 1
+        return replace(self.stats, by_kind=dict(by_kind))
+
+    def _record_issues(self, path: Path, issues: list[ParseIssue]) -> None:
+        """Replace the problems recorded for a file, and log them."""
+        if not issues:
+            self._issues.pop(path, None)
+            return
+        for issue in issues:
+            logger.warning(f"{path}: {issue}")
+        self._issues[path] = issues
+
+    def _resolve(self, path: Path) -> Object | None:
+        """Resolve the object of a collected path, or None if its file could not be parsed."""
+        try:
+            return self._objects[path].target
+        except ValueError:
+            if path in self._issues:
+                return None
+            raise
+
+    def _normalize(self, path: Path) -> Path:
+        """
+        Normalize a path that is added to or removed from the search path.
+
+        Args:
+            path (Path): The path to normalize.
+
+        Returns:
+            Path: The path as given, or resolved against the working directory.
+        """
+        if self._path_normalization is PathNormalization.none:
+            return path
+        return (self._working_directory / path).resolve()
+
+    def _casefold(self, identifier: str) -> str:
+        """
+        Find the identifier in the mapping that matches an identifier case-insensitively.
+
+        Args:
+            identifier (str): The identifier to look up.
+
+        Returns:
+            str: The matching identifier, or the identifier itself if there is no match.
+
+        Raises:
+            AmbiguousNameError: If several identifiers differ from it only by case.
+        """
+        candidates = self._casefolded.get(identifier.casefold(), [])
+        if len(candidates) > 1:
+            raise AmbiguousNameError(identifier, candidates)
+        return candidates[0] if candidates else identifier
+
+    def _is_excluded(self, path: Path) -> bool:
+        """Whether a path is left out because of ignore files, or it was skipped or removed."""
+        return (
+            path in self._ignored
+            or path in self._skipped
+            or path in self._removed
+            or not self._is_selected(path)
+        )
+
+    def _is_selected(self, path: Path) -> bool:
+        """
+        Whether a path is collected when only some files are selected, see `parse_files`.
+
+        Args:
+            path (Path): The file or directory.
+
+        Returns:
+            bool: Whether no files are selected, or the path is a selected file, a directory
+                containing one, a file of the class folder of one, or a `Contents.m` file.
+        """
+        if self._selected is None or path.name == CONTENTS_FILE:
+            return True
+        return any(
+            _is_subdirectory(path, file)
+            or (path.parent == file.parent and file.parent.name[:1] == CLASSFOLDER_PREFIX)
+            for file in self._selected
+        )
+
     def get_member(self, identifier: str, working_directory: Path | None = None) -> Any:
         if (
             working_directory is not None
@@ -481,6 +1036,34 @@ class PathsCollection:
         else:
             return None
 
+    def source_path(self, identifier: str) -> Path | None:
+        """
+        Return the source file in which an object is defined.
+
+        For class folders, this is the file containing the class definition, if any.
+        For namespaces and class folders without a class definition file, this is the folder itself.
+
+        Args:
+            identifier (str): The identifier of the object.
+
+        Returns:
+            Path | None: The source path, or None if the identifier is not in the collection.
+        """
+        path = self.get_path(identifier)
+        if path is None:
+            object = self[identifier]
+            if object is None:
+                return None
+            try:
+                return object.filepath
+            except FilePathError:
+                return None
+        if path.is_dir() and path.name[0] == CLASSFOLDER_PREFIX:
+            classfile = path / f"{path.name[1:]}{MFILE_SUFFIX}"
+            if classfile.exists():
+                return classfile
+        return path
+
     def __contains__(self, identifier: str) -> bool:
         """
         Check if the identifier exists in the collection.
@@ -493,7 +1076,7 @@ class PathsCollection:
         """
         try:
             return self.__getitem__(identifier) is not None
-        except KeyError:
+        except (KeyError, AmbiguousNameError):
             return False
 
     def __getitem__(self, identifier: str) -> Any:
@@ -510,7 +1093,16 @@ class PathsCollection:
 
         Returns:
             Object or None: The resolved Object object if found, otherwise None.
+
+        Raises:
+            AmbiguousNameError: If the identifier is looked up case-insensitively and matches
+                several identifiers that differ only by case.
         """
+        if (
+            self._path_normalization is PathNormalization.case_insensitive
+            and identifier not in self._mapping
+        ):
+            identifier = self._casefold(identifier)
 
         # Find in global database
         if identifier in self._mapping:
@@ -546,37 +1138,102 @@ class PathsCollection:
                 object = None
 
         else:
-            object = None
+            object = self._resolve_alias(identifier)
             name_parts = identifier.split(".")
-            if len(name_parts) > 1:
+            if object is None and len(name_parts) > 1:
                 base = self.get_member(".".join(name_parts[:-1]))
-                if base is None or name_parts[-1] not in base.members:
-                    object = None
-                else:
-                    object = base.members[name_parts[-1]]
-            else:
-                object = None
+                object = self._nested_member(base, name_parts[-1:])
 
         if isinstance(object, Alias):
             return object.target
         return object
 
-    def addpath(self, path: str | Path, to_end: bool = False, recursive: bool = False):
+    def resolve_qualified(self, qualified_name: str) -> Object | None:
+        """
+        Resolve a dotted name by walking the namespaces and classes it consists of.
+
+        The first part of the name is looked up as a namespace, or otherwise as a top-level object,
+        and each following part as a member of the previous one. For example, ``ns.sub.MyClass``
+        gets the namespace ``+ns``, its member namespace ``sub``, and then its member ``MyClass``.
+
+        Args:
+            qualified_name (str): The dotted name, e.g. ``ns.sub.MyClass.method``.
+
+        Returns:
+            Object | None: The object the last part of the name refers to, or None if any of the
+                parts is not found.
+        """
+        head, *parts = qualified_name.split(".")
+        object = self[f"{NAMESPACE_PREFIX}{head}"]
+        if object is None:
+            object = self[head]
+        return self._nested_member(object, parts)
+
+    @staticmethod
+    def _nested_member(object: Any, parts: list[str]) -> Any:
+        """
+        Get a member of an object by walking the members named by the parts of a dotted name.
+
+        Args:
+            object (Any): The object to start from, or None.
+            parts (list[str]): The names of the nested members, e.g. ``["sub", "MyClass"]``.
+
+        Returns:
+            Any: The object the last part refers to, or None if any of the parts is not found.
+        """
+        for part in parts:
+            if object is None or part not in object.members:
+                return None
+            object = object.members[part]
+            if isinstance(object, Alias):
+                object = object.target
+        return object
+
+    def _resolve_alias(self, identifier: str) -> Alias | None:
+        """
+        Resolve an identifier that starts with a logical name from the path aliases.
+
+        Args:
+            identifier (str): The identifier to resolve, e.g. ``mylib.func``.
+
+        Returns:
+            Alias | None: The object defined under the mapped directory, or None.
+        """
+        for name, root in self._aliases.items():
+            if not identifier.startswith(f"{name}."):
+                continue
+            for path in self._mapping.get(identifier[len(name) + 1 :], ()):
+                if path.is_relative_to(root):
+                    return self._objects[path]
+        return None
+
+    def addpath(
+        self,
+        path: str | Path,
+        to_end: bool = False,
+        recursive: bool = False,
+        max_depth: int | None = None,
+    ):
         """
         Add a path to the search path.
 
         Args:
             path (str | Path): The path to be added.
             to_end (bool, optional): Whether to add the path to the end of the search path. Defaults to False.
+            recursive (bool, optional): Whether to also add the subdirectories of the path. Defaults to False.
+            max_depth (int | None, optional): The number of directory levels to add, overriding
+                `recursive`, where 1 adds only the path itself. Defaults to None.
 
         Returns:
             list[Path]: The previous search path before adding the new path.
         """
         if isinstance(path, str):
             path = Path(path)
+        path = self._normalize(path)
 
         if path in self._path:
             self._path.remove(path)
+            self._members.pop(path, None)
 
         if to_end:
             self._path.append(path)
@@ -585,32 +1242,65 @@ class PathsCollection:
             self._path.appendleft(path)
             logger.info(f"Added path to start: {path}")
 
-        for member in _PathGlobber(
-            path, recursive=recursive, parse_live_scripts=self._parse_live_scripts
-        ):
+        respect_gitignore = self._respect_gitignore
+        if respect_gitignore is None:
+            respect_gitignore = _git_root(path.absolute()) is not None
+        start = time.perf_counter()
+        globber = _PathGlobber(
+            path,
+            recursive=recursive,
+            parse_live_scripts=self._parse_live_scripts,
+            ignore=_IgnoreRules.for_path(path) if respect_gitignore else None,
+            max_file_size=self._max_file_size,
+            max_depth=max_depth,
+        )
+        walk_time = time.perf_counter() - start
+        logger.trace(f"Walked {path} ({len(globber)} members) in {walk_time:.3f} s")
+        self._stats.walk_time += walk_time
+        self._ignored.update(globber.ignored)
+        for member, size in globber.oversized.items():
+            reason = f"file size {size} exceeds the maximum of {self._max_file_size} bytes"
+            logger.warning(f"Skipped {member}: {reason}")
+            self._skipped[member] = reason
+
+        # Files in the added path itself take precedence over those in its subdirectories.
+        members = sorted(
+            (member for member in globber if self._is_selected(member)),
+            key=lambda member: len(member.relative_to(path).parts),
+        )
+        self._removed.difference_update(members)
+        for member in members:
             object = Alias(member.stem, target=_PathResolver(member, self))
             self._objects[member] = object
 
-        for member, object in self._objects.items():
+        for member in members:
+            object = self._objects[member]
             if (CLASSFOLDER_PREFIX + member.stem) == member.parent.name:
                 # skip class file in class folder, this member is added via the class folder
                 continue
 
             if member.is_dir() and member.stem[0] not in FOLDER_PREFIXES:
                 self._folders[member] = object
+            elif self._resolve(member) is None:
+                # The file could not be read or parsed, its problems are recorded as issues.
+                continue
             else:
-                self._mapping[object.path].append(member)
-                self._members[path].append((object.name, member))
+                self._members[path].append((object.path, member))
 
             if not self._local and member.is_file():
                 if member.parent not in self._local_collections:
                     self._local_collections[member.parent] = PathsCollection.as_local_collection(
-                        member.parent, parser_config=self._parser_config
+                        member.parent,
+                        parser_config=self._parser_config,
+                        tolerant=self._tolerant,
+                        path_normalization=self._path_normalization,
                     )
                 local_collection = self._local_collections[member.parent]
                 local_collection._objects[member] = object
                 local_collection._mapping[member.stem].append(member)
 
+        self._update_mapping()
+
         for member, collection in self._local_collections.items():
             if member.stem[0] == CLASSFOLDER_PREFIX:
                 object = self._objects[member]
@@ -618,6 +1308,60 @@ class PathsCollection:
                     if name not in object.members:
                         object.members[name] = child
 
+    def precedence_order(self, identifier: str) -> list[Path]:
+        """
+        Return all source files that define an identifier, in search path order.
+
+        As in MATLAB, the first file in the returned list is the one that the identifier resolves to,
+        and the remaining files are shadowed by it.
+
+        Args:
+            identifier (str): The identifier to look up.
+
+        Returns:
+            list[Path]: The source files defining the identifier, ordered by priority.
+        """
+        return list(self._mapping.get(identifier, ()))
+
+    def private_member(self, directory: str | Path, name: str) -> Object | None:
+        """
+        Find a function in the `private` folder of a directory.
+
+        In MATLAB, such functions can only be called from the files of the directory and of the
+        `private` folder itself, where they take precedence over the functions on the search path.
+
+        Args:
+            directory (str | Path): The directory containing the `private` folder.
+            name (str): The name of the function.
+
+        Returns:
+            Object | None: The private function, or None if there is none.
+        """
+        if isinstance(directory, str):
+            directory = Path(directory)
+        directory = self._normalize(directory)
+        local_collection = self._local_collections.get(directory)
+        if local_collection is None:
+            return None
+        for path in local_collection.precedence_order(name):
+            if path.parent == directory / PRIVATE_FOLDER:
+                return local_collection._resolve(path)
+        return None
+
+    def _update_mapping(self) -> None:
+        """Rebuild the identifier mapping following the order of the search path."""
+        mapping: dict[str, deque[Path]] = defaultdict(deque)
+        for path in self._path:
+            for name, member in self._members.get(path, []):
+                if member in self._objects and member not in mapping[name]:
+                    mapping[name].append(member)
+        self._mapping = mapping
+        if self._path_normalization is PathNormalization.case_insensitive:
+            casefolded: dict[str, list[str]] = defaultdict(list)
+            for name in mapping:
+                casefolded[name.casefold()].append(name)
+            self._casefolded = dict(casefolded)
+
     def rmpath(self, path: str | Path, recursive: bool = False):
         """
         Removes a path from the search path and updates the namespace and database accordingly.
@@ -632,6 +1376,7 @@ class PathsCollection:
         """
         if isinstance(path, str):
             path = Path(path)
+        path = self._normalize(path)
 
         if path not in self._path:
             logger.warning(f"Attempted to remove path not in search path: {path}")
@@ -640,9 +1385,9 @@ class PathsCollection:
         self._path.remove(path)
         logger.info(f"Removed path: {path}")
 
-        for name, member in self._members.pop(path):
-            self._mapping[name].remove(member)
-            self._objects.pop(member)
+        for _, member in self._members.pop(path, []):
+            self._objects.pop(member, None)
+        self._update_mapping()
 
         if path in self._local_collections:
             self._local_collections.pop(path)
@@ -651,6 +1396,283 @@ class PathsCollection:
             for subdir in [item for item in self._path if _is_subdirectory(path, item)]:
                 self.rmpath(subdir, recursive=False)
 
+    def remove(self, identifier: str) -> Object | None:
+        """
+        Remove an object from the collection, e.g. when its source file is deleted.
+
+        Only the definition that the identifier resolves to is removed, so that a definition it
+        shadowed further down the search path becomes visible.
+
+        Args:
+            identifier (str): The identifier of the object to remove.
+
+        Returns:
+            Object | None: The removed object, or None if the identifier is not in the collection
+                or its file could not be parsed.
+        """
+        if identifier not in self._mapping:
+            return None
+        path = self._mapping[identifier][0]
+        object = self._resolve(path)
+        self._evict({path})
+        logger.info(f"Removed {identifier} defined in {path}")
+        return object
+
+    def remove_path(self, path: str | Path) -> list[str]:
+        """
+        Remove all objects defined in a file or directory from the collection.
+
+        Unlike [`rmpath`][maxx.collection.PathsCollection.rmpath], the search path itself is left
+        unchanged, so that the objects of a deleted file can be evicted while its directory stays on
+        the path. Removing the class definition file of a class folder removes the whole class.
+
+        Args:
+            path (str | Path): The source file or directory of the objects to remove.
+
+        Returns:
+            list[str]: The identifiers of the removed objects.
+        """
+        if isinstance(path, str):
+            path = Path(path)
+        path = self._normalize(path)
+
+        roots = [path]
+        if path.parent.name == f"{CLASSFOLDER_PREFIX}{path.stem}":
+            roots.append(path.parent)
+        members = {
+            member
+            for member in self._objects
+            if any(_is_subdirectory(root, member) for root in roots)
+        }
+        names = self._evict(members)
+        logger.info(f"Removed {len(names)} objects defined in {path}")
+        return names
+
+    def _evict(self, members: set[Path]) -> list[str]:
+        """
+        Remove collected files and folders, and the objects they define, from the collection.
+
+        The folders, class folders and namespaces containing the removed paths are collected
+        again, so that they no longer list the removed objects.
+
+        Args:
+            members (set[Path]): The collected paths to remove.
+
+        Returns:
+            list[str]: The identifiers of the objects that were defined by the removed paths.
+        """
+        names: list[str] = []
+        for path, entries in self._members.items():
+            names.extend(name for name, member in entries if member in members)
+            self._members[path] = [entry for entry in entries if entry[1] not in members]
+
+        for member in members:
+            self._objects.pop(member, None)
+            self._folders.pop(member, None)
+            self._issues.pop(member, None)
+            self._fingerprints.pop(member, None)
+            self._local_collections.pop(member, None)
+            self.lines_collection._data.pop(member, None)
+            local_collection = self._local_collections.get(member.parent)
+            if local_collection is not None and member in local_collection._objects:
+                local_collection._objects.pop(member)
+                local_collection._mapping[member.stem].remove(member)
+                if not local_collection._mapping[member.stem]:
+                    local_collection._mapping.pop(member.stem)
+
+        self._removed.update(members)
+        for member in members:
+            self._invalidate(member)
+        self._update_mapping()
+        return names
+
+    def refresh(self) -> list[Path]:
+        """
+        Parse the files that changed since they were parsed again, and remove deleted files.
+
+        Changes are detected following the cache strategy of the collection. The folders, class
+        folders and namespaces containing a changed file are collected again as well. Files that
+        were added since are not picked up, add their directory again with
+        [`addpath`][maxx.collection.PathsCollection.addpath] instead.
+
+        Returns:
+            list[Path]: The files that changed or were deleted.
+        """
+        changed = []
+        for path in list(self._fingerprints):
+            if path.exists() and not self._is_stale(path):
+                continue
+            changed.append(path)
+            self._reload(path)
+
+        for path in changed:
+            if path in self._objects:
+                self._resolve(path)
+        if changed:
+            logger.info(f"Refreshed {len(changed)} changed files")
+        return changed
+
+    def reload_file(self, path: str | Path) -> Object | None:
+        """
+        Parse a single file again, e.g. when a file watcher reports that it changed.
+
+        Unlike [`refresh`][maxx.collection.PathsCollection.refresh], no other file is checked for
+        changes. The folders, class folders and namespaces containing the file are collected
+        again as well. A file that no longer exists is removed from the collection.
+
+        Args:
+            path (str | Path): The collected file to parse again.
+
+        Returns:
+            Object | None: The object the file defined before, or None if the file is not in the
+                collection or had not been parsed yet.
+        """
+        if isinstance(path, str):
+            path = Path(path)
+        path = self._normalize(path)
+        if path not in self._objects:
+            logger.warning(f"Attempted to reload file not in collection: {path}")
+            return None
+
+        alias = self._objects[path]
+        old = alias.target if alias.resolved else None
+        self._reload(path)
+        if path in self._objects:
+            self._resolve(path)
+            logger.info(f"Reloaded {path}")
+        return old
+
+    def _reload(self, path: Path) -> None:
+        """
+        Remove a deleted file from the collection, or mark a changed file to be parsed again.
+
+        Either way, the folders, class folders and namespaces containing the file are collected
+        again when they are next resolved.
+
+        Args:
+            path (Path): The changed or deleted file.
+        """
+        if path.exists():
+            self._invalidate(path)
+        else:
+            self.remove_path(path)
+            self._fingerprints.pop(path, None)
+
+    def _fingerprint(self, path: Path) -> tuple[int | None, str | None]:
+        """
+        Compute the fingerprint of a file that is compared to detect changes.
+
+        Args:
+            path (Path): The file.
+
+        Returns:
+            tuple[int | None, str | None]: The modification time in nanoseconds and the hash of
+                the content, each None if the cache strategy does not use it.
+        """
+        mtime = None
+        if self._cache_strategy is not CacheStrategy.content_hash:
+            mtime = path.stat().st_mtime_ns
+        digest = None
+        if self._cache_strategy is not CacheStrategy.mtime:
+            digest = _content_hash(path)
+        return mtime, digest
+
+    def _record_fingerprint(self, path: Path) -> None:
+        """Record the fingerprint of a file that is about to be parsed."""
+        try:
+            self._fingerprints[path] = self._fingerprint(path)
+        except OSError:
+            self._fingerprints.pop(path, None)
+
+    def _is_stale(self, path: Path) -> bool:
+        """
+        Whether a file changed since it was parsed.
+
+        Args:
+            path (Path): The file, which must have been parsed.
+
+        Returns:
+            bool: Whether the file must be parsed again.
+        """
+        mtime, digest = self._fingerprints[path]
+        if self._cache_strategy is not CacheStrategy.content_hash:
+            current = path.stat().st_mtime_ns
+            if current == mtime:
+                return False
+            if self._cache_strategy is CacheStrategy.mtime:
+                return True
+            # The file was touched, its content decides whether it changed.
+            self._fingerprints[path] = (current, digest)
+        return _content_hash(path) != digest
+
+    def _invalidate(self, path: Path) -> None:
+        """
+        Replace the objects of a file and of the directories containing it with unresolved ones.
+
+        Args:
+            path (Path): The changed or deleted file.
+        """
+        for member in (path, *path.parents):
+            if member not in self._objects:
+                continue
+            alias = Alias(member.stem, target=_PathResolver(member, self))
+            self._objects[member] = alias
+            if member in self._folders:
+                self._folders[member] = alias
+            local_collection = self._local_collections.get(member.parent)
+            if local_collection is not None and member in local_collection._objects:
+                local_collection._objects[member] = alias
+
+
+def parse_directory(
+    path: str | Path, recursive: bool = False, max_depth: int | None = None, **kwargs: Any
+) -> PathsCollection:
+    """
+    Collect the MATLAB objects in a directory.
+
+    Args:
+        path: The directory to collect.
+        recursive: Whether to also collect the subdirectories of the directory.
+        max_depth: The number of directory levels to collect, overriding `recursive`.
+        **kwargs: Further arguments passed to [`PathsCollection`][maxx.collection.PathsCollection].
+
+    Returns:
+        The collection of the directory.
+    """
+    return PathsCollection([path], recursive=recursive, max_depth=max_depth, **kwargs)
+
+
+def parse_files(paths: Sequence[str | Path], **kwargs: Any) -> PathsCollection:
+    """
+    Collect the MATLAB objects defined in a list of files.
+
+    The files are collected from the directories on which they would be on the MATLAB path,
+    i.e. above their namespace, class and private folders, so that they are named and resolved
+    as in MATLAB. Other files in these directories are neither parsed nor collected, except for
+    the methods of a class folder whose class definition file is listed.
+
+    Args:
+        paths: The files to collect.
+        **kwargs: Further arguments passed to [`PathsCollection`][maxx.collection.PathsCollection].
+
+    Returns:
+        The collection of the files.
+    """
+    files = [Path(path) for path in paths]
+    roots: list[Path] = []
+    for file in files:
+        root = file.parent
+        while root.name and (root.name[0] in FOLDER_PREFIXES or root.name == PRIVATE_FOLDER):
+            root = root.parent
+        if root not in roots:
+            roots.append(root)
+
+    collection = PathsCollection([], **kwargs)
+    collection._selected = {collection._normalize(file) for file in files}
+    for root in roots:
+        collection.addpath(root, to_end=True)
+    return collection
+
 
 def _is_subdirectory(parent_path: Path, child_path: Path) -> bool:
     """
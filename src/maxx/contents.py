@@ -0,0 +1,135 @@
+"""Check that `Contents.m` files list the files of their directory."""
+
+from __future__ import annotations
+
+import re
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from maxx.enums import ContentsMismatchKind
+from maxx.exceptions import AmbiguousNameError
+
+if TYPE_CHECKING:
+    from maxx.collection import PathsCollection
+    from maxx.objects import Object
+
+__all__ = ["ContentsMismatch", "check_contents_file"]
+
+CONTENTS_ENTRY = re.compile(r"^%\s*([A-Za-z]\w*)\s+-\s*(.*?)\s*$")
+"""A line of a `Contents.m` file listing a file and its description, e.g. `%   func - Do it.`"""
+
+
+@dataclass(frozen=True)
+class ContentsMismatch:
+    """A disagreement between a `Contents.m` file and the files of its directory."""
+
+    kind: ContentsMismatchKind
+    """Whether a file is missing, a listed name is stale, or a description differs."""
+    name: str
+    """The name of the file."""
+    contents_description: str | None = None
+    """The description listed in the `Contents.m` file, if any."""
+    actual_description: str | None = None
+    """The description in the H1 line of the file, if any."""
+
+    def __str__(self) -> str:
+        if self.kind is ContentsMismatchKind.missing:
+            return f"{self.name} is not listed"
+        if self.kind is ContentsMismatchKind.stale:
+            return f"{self.name} is listed but does not exist"
+        return (
+            f"{self.name} is listed as {self.contents_description!r}, "
+            f"but its H1 line is {self.actual_description!r}"
+        )
+
+
+def _normalize(text: str) -> str:
+    return " ".join(text.split())
+
+
+def _h1_description(name: str, h1_line: str) -> str:
+    """
+    Get the description in the H1 line of a file.
+
+    Args:
+        name: The name of the documented file, which the H1 line may start with.
+        h1_line: The H1 line.
+
+    Returns:
+        The H1 line, without the leading name.
+    """
+    first, _, rest = h1_line.partition(" ")
+    if first.casefold() == name.casefold():
+        h1_line = rest
+    return _normalize(h1_line)
+
+
+def _collected_object(file: Path, paths_collection: PathsCollection) -> Object | None:
+    """
+    Get the object defined in a file of the collection.
+
+    Args:
+        file: The file.
+        paths_collection: The collection.
+
+    Returns:
+        The object, or None if the file is not collected or could not be parsed.
+    """
+    try:
+        object = paths_collection.get_member(file.stem, working_directory=file.parent)
+    except (KeyError, AmbiguousNameError):
+        return None
+    if object is None or object.filepath != file:
+        return None
+    return object
+
+
+def check_contents_file(path: Path, paths_collection: PathsCollection) -> list[ContentsMismatch]:
+    """
+    Check that a `Contents.m` file lists the files of its directory with their H1 lines.
+
+    Entries are the comment lines of the form `% name - description`. Files that are ignored,
+    skipped or could not be parsed by the collection are not expected to be listed.
+
+    Args:
+        path: The `Contents.m` file.
+        paths_collection: The collection from which the files of the directory are resolved.
+
+    Returns:
+        The mismatches, with the missing and differing files in alphabetical order, followed by
+        the stale entries in order of appearance.
+    """
+    listed: dict[str, str] = {}
+    for line in path.read_text(encoding="utf-8", errors="replace").splitlines():
+        match = CONTENTS_ENTRY.match(line.strip())
+        if match is not None:
+            listed.setdefault(match.group(1), _normalize(match.group(2)))
+
+    mismatches: list[ContentsMismatch] = []
+    names: set[str] = set()
+    for file in sorted(path.parent.glob("*.m")):
+        if file == path:
+            continue
+        object = _collected_object(file, paths_collection)
+        if object is None:
+            continue
+        names.add(file.stem)
+        if file.stem not in listed:
+            mismatches.append(ContentsMismatch(ContentsMismatchKind.missing, file.stem))
+            continue
+        if object.h1_line is None:
+            continue
+        actual = _h1_description(file.stem, object.h1_line)
+        if actual != listed[file.stem]:
+            mismatches.append(
+                ContentsMismatch(
+                    ContentsMismatchKind.description, file.stem, listed[file.stem], actual
+                )
+            )
+
+    for name in listed:
+        if name not in names and not (path.parent / f"{name}.m").exists():
+            mismatch = ContentsMismatch(ContentsMismatchKind.stale, name, listed[name])
+            mismatches.append(mismatch)
+    return mismatches
@@ -0,0 +1,217 @@
+"""Generate Markdown documentation from parsed MATLAB objects."""
+
+from __future__ import annotations
+
+from pathlib import Path
+from typing import TYPE_CHECKING
+
+from loguru import logger
+
+from maxx.enums import ArgumentKind
+from maxx.exceptions import CyclicAliasError
+from maxx.objects import (
+    Alias,
+    Arguments,
+    Class,
+    ClassFolder,
+    Function,
+    Object,
+    Property,
+    Validatable,
+)
+
+if TYPE_CHECKING:
+    from maxx.collection import PathsCollection
+
+__all__ = ["generate", "to_markdown"]
+
+
+def _cell(text: object) -> str:
+    """
+    Format a value as the content of a Markdown table cell.
+
+    Args:
+        text: The value to format. None gives an empty cell.
+
+    Returns:
+        The text on a single line, with pipes escaped.
+    """
+    if text is None:
+        return ""
+    return " ".join(str(text).split()).replace("|", "\\|")
+
+
+def _summary(object: Object | Validatable) -> str:
+    """
+    Get the first paragraph of the docstring of an object.
+
+    Args:
+        object: The object to summarize.
+
+    Returns:
+        The first paragraph of the docstring, or an empty string if there is none.
+    """
+    if object.docstring is None:
+        return ""
+    return object.docstring.value.strip().split("\n\n")[0]
+
+
+def _signature(object: Object) -> str | None:
+    """
+    Build the MATLAB declaration of a function or class.
+
+    Args:
+        object: The object to build the declaration for.
+
+    Returns:
+        The declaration, or None for objects that are not declared, such as namespaces.
+    """
+    if isinstance(object, Function):
+        inputs = []
+        for argument in object.arguments:
+            if argument.kind is ArgumentKind.keyword_only:
+                default = argument.default if argument.default is not None else "value"
+                inputs.append(f"{argument.name}={default}")
+            else:
+                inputs.append(argument.name)
+        outputs = [argument.name for argument in object.returns]
+        declaration = f"{object.name}({', '.join(inputs)})"
+        if len(outputs) == 1:
+            declaration = f"{outputs[0]} = {declaration}"
+        elif outputs:
+            declaration = f"[{', '.join(outputs)}] = {declaration}"
+        return f"function {declaration}"
+    if isinstance(object, Class):
+        bases = " & ".join(object.bases)
+        return f"classdef {object.name}" + (f" < {bases}" if bases else "")
+    return None
+
+
+def _arguments_table(arguments: Arguments | list[Property]) -> list[str]:
+    """
+    Build a Markdown table of arguments or properties.
+
+    Args:
+        arguments: The arguments or properties to list.
+
+    Returns:
+        The lines of the table.
+    """
+    lines = ["| Name | Type | Default | Description |", "| --- | --- | --- | --- |"]
+    for argument in arguments:
+        lines.append(
+            f"| `{argument.name}` | {_cell(argument.type)} | {_cell(argument.default)} "
+            f"| {_cell(_summary(argument))} |"
+        )
+    return lines
+
+
+def _members_table(members: list[Object]) -> list[str]:
+    """
+    Build a Markdown table of members with their summaries.
+
+    Args:
+        members: The members to list.
+
+    Returns:
+        The lines of the table.
+    """
+    lines = ["| Name | Description |", "| --- | --- |"]
+    for member in members:
+        lines.append(f"| `{member.name}` | {_cell(_summary(member))} |")
+    return lines
+
+
+def _resolved_members(object: Object) -> list[Object]:
+    """
+    Get the members of an object, with aliases resolved.
+
+    Args:
+        object: The object whose members to get.
+
+    Returns:
+        The members that could be resolved.
+    """
+    members = []
+    for member in object.members.values():
+        if isinstance(member, Alias):
+            try:
+                member = member.target
+            except (CyclicAliasError, ValueError):
+                continue
+        if member is not None:
+            members.append(member)
+    return members
+
+
+def to_markdown(object: Object) -> str:
+    """
+    Generate a Markdown document for a MATLAB object.
+
+    The document has a heading with the name and kind of the object, its declaration, its
+    docstring, and tables of its arguments and outputs, or of its properties and methods.
+
+    Args:
+        object: The function, class, namespace or other object to document.
+
+    Returns:
+        The Markdown document.
+    """
+    if isinstance(object, ClassFolder) and object.classfile is not None:
+        object = object.classfile
+
+    lines = [f"# {object.name}", "", f"*{object.kind.value}*", ""]
+
+    signature = _signature(object)
+    if signature is not None:
+        lines += ["```matlab", signature, "```", ""]
+
+    if object.docstring is not None and object.docstring.value.strip():
+        lines += [object.docstring.value.strip(), ""]
+
+    if isinstance(object, Function):
+        if len(object.arguments):
+            lines += ["## Arguments", "", *_arguments_table(object.arguments), ""]
+        if len(object.returns):
+            lines += ["## Returns", "", *_arguments_table(object.returns), ""]
+    elif isinstance(object, Class):
+        members = _resolved_members(object)
+        properties = [member for member in members if isinstance(member, Property)]
+        methods = [member for member in members if isinstance(member, Function)]
+        if properties:
+            lines += ["## Properties", "", *_arguments_table(properties), ""]
+        if methods:
+            lines += ["## Methods", "", *_members_table(methods), ""]
+    else:
+        members = _resolved_members(object)
+        if members:
+            lines += ["## Members", "", *_members_table(members), ""]
+
+    return "\n".join(lines).rstrip() + "\n"
+
+
+def generate(paths_collection: "PathsCollection", output_dir: Path) -> list[Path]:
+    """
+    Write a Markdown document for every member of a collection.
+
+    Each document is named after the dotted path of the object, e.g. `+ns/func.m` is written
+    to `ns.func.md` and the namespace `+ns` itself to `+ns.md`.
+
+    Args:
+        paths_collection: The collection to document.
+        output_dir: The directory in which to write the documents. It is created if needed.
+
+    Returns:
+        The paths of the written documents.
+    """
+    output_dir.mkdir(parents=True, exist_ok=True)
+    written = []
+    for identifier, alias in paths_collection.members.items():
+        object = alias.target
+        if object is None:
+            continue
+        path = output_dir / f"{identifier}.md"
+        path.write_text(to_markdown(object), encoding="utf-8")
+        logger.debug(f"Wrote documentation for {identifier} to {path}")
+        written.append(path)
+    return written
@@ -2,10 +2,50 @@
 
 from __future__ import annotations
 
+import sys
 from enum import Enum
+from typing import TypeVar
 
+from maxx.exceptions import MaltError
 
-class Kind(str, Enum):
+_E = TypeVar("_E", bound="StrEnum")
+
+
+class StrEnum(str, Enum):
+    """
+    An enumeration of strings, which are formatted as and parsed from their values.
+
+    For example, `str(Kind.FUNCTION)` and `f"{Kind.FUNCTION}"` give `"function"`, and
+    `Kind.from_str("function")` gives `Kind.FUNCTION`.
+    """
+
+    def __str__(self) -> str:
+        return self.value
+
+    @classmethod
+    def from_str(cls: type[_E], value: str) -> _E:
+        """
+        Get the member with the given value.
+
+        Args:
+            value: The value of the member, e.g. `"function"`.
+
+        Returns:
+            The member.
+
+        Raises:
+            MaltError: If no member has the value.
+        """
+        try:
+            return cls(value)
+        except ValueError:
+            expected = ", ".join(dict.fromkeys(member.value for member in cls))
+            raise MaltError(
+                f"Invalid {cls.__name__} {value!r}, expected one of: {expected}"
+            ) from None
+
+
+class Kind(StrEnum):
     """
     An enumeration representing different kinds of MATLAB code elements.
     This enumeration is a subclass of the Griffe `Kind` enumeration, and extends it with additional values.
@@ -33,7 +73,7 @@ class Kind(str, Enum):
     """Live scripts."""
 
 
-class ArgumentKind(str, Enum):
+class ArgumentKind(StrEnum):
     """
     An enumeration representing different kinds of function arguments.
 
@@ -50,7 +90,7 @@ class ArgumentKind(str, Enum):
     varargin = "varargin"
 
 
-class AccessKind(str, Enum):
+class AccessKind(StrEnum):
     """
     An enumeration representing different access levels for MATLAB code elements.
 
@@ -65,3 +105,114 @@ class AccessKind(str, Enum):
     protected = "protected"
     private = "private"
     immutable = "immutable"
+
+
+class ReferenceKind(StrEnum):
+    """
+    An enumeration representing what an identifier in an expression refers to.
+
+    Attributes:
+        builtin (str): A MATLAB builtin, documented by MathWorks.
+        object (str): An object in the paths collection.
+        variable (str): A variable of the function, i.e. an argument, output or assigned name.
+        local_function (str): A local function in the same file.
+        private_function (str): A function in the `private` folder next to the file.
+        unknown (str): An identifier that could not be resolved.
+    """
+
+    builtin = "builtin"
+    object = "object"
+    variable = "variable"
+    local_function = "local function"
+    private_function = "private function"
+    unknown = "unknown"
+
+
+class ParseIssueKind(StrEnum):
+    """
+    An enumeration representing the problems encountered while parsing a file.
+
+    Attributes:
+        io (str): The file could not be read.
+        encoding (str): The encoding of the file could not be detected or decoded.
+        syntax (str): The file contains a syntax error, or could not be parsed.
+    """
+
+    io = "io"
+    encoding = "encoding"
+    syntax = "syntax"
+
+
+class ContentsMismatchKind(StrEnum):
+    """
+    An enumeration representing how a `Contents.m` file disagrees with its directory.
+
+    Attributes:
+        missing (str): A file in the directory is not listed.
+        stale (str): A listed name has no file in the directory.
+        description (str): The listed description differs from the H1 line of the file.
+    """
+
+    missing = "missing"
+    stale = "stale"
+    description = "description"
+
+
+class TestRole(StrEnum):
+    """
+    An enumeration representing the role of a method in a `matlab.unittest.TestCase` class.
+
+    The values are the attributes of the `methods` block that declares the method.
+
+    Attributes:
+        test (str): A test, declared in a `methods (Test)` block.
+        method_setup (str): A fixture run before each test.
+        method_teardown (str): A fixture run after each test.
+        class_setup (str): A fixture run once before the tests of the class.
+        class_teardown (str): A fixture run once after the tests of the class.
+    """
+
+    test = "Test"
+    method_setup = "TestMethodSetup"
+    method_teardown = "TestMethodTeardown"
+    class_setup = "TestClassSetup"
+    class_teardown = "TestClassTeardown"
+
+
+class PathNormalization(StrEnum):
+    """
+    An enumeration representing how the paths added to a collection are normalized.
+
+    Attributes:
+        none (str): Paths are used as given.
+        resolve (str): Paths are made absolute, with `..` and symbolic links resolved, so that
+            different spellings of the same directory are collected once.
+        case_insensitive (str): Paths are resolved, and identifiers that are not found are
+            looked up case-insensitively, as MATLAB does on Windows.
+    """
+
+    none = "none"
+    resolve = "resolve"
+    case_insensitive = "case-insensitive"
+
+    @classmethod
+    def default(cls) -> PathNormalization:
+        """The normalization for the current platform: case-insensitive on Windows, else none."""
+        return cls.case_insensitive if sys.platform == "win32" else cls.none
+
+
+class CacheStrategy(StrEnum):
+    """
+    An enumeration representing how a collection detects the files that changed after parsing.
+
+    Attributes:
+        mtime (str): Files are parsed again when their modification time changed.
+        content_hash (str): Files are parsed again when the hash of their content changed, which
+            also works when version control restores files with their old modification time.
+        both (str): Files are parsed again when both their modification time and the hash of
+            their content changed, so that files that are only touched are not parsed again.
+    """
+
+    mtime = "mtime"
+    content_hash = "content-hash"
+    both = "both"
@@ -0,0 +1,130 @@
+"""Extract the code examples of docstrings, and check their syntax."""
+
+from __future__ import annotations
+
+import re
+import textwrap
+from dataclasses import dataclass
+from pathlib import Path
+
+from griffe import Docstring
+
+from maxx.enums import ParseIssueKind
+from maxx.treesitter import FileParser, ParseIssue
+
+__all__ = ["Example", "check_examples", "extract_examples"]
+
+EXAMPLE_HEADER = re.compile(r"^\s*examples?\s*:?\s*$", re.IGNORECASE)
+"""A line starting an examples section, e.g. `Example:` or `Examples`."""
+PROMPT = ">>"
+"""The command prompt preceding the lines of an example, as in the MATLAB command window."""
+NO_CHECK = re.compile(r"%\s*no-check\s*$")
+"""A trailing comment marking an example that is not syntax-checked."""
+
+
+@dataclass(frozen=True)
+class Example:
+    """A code example in a docstring."""
+
+    code: str
+    """The code of the example, without prompts and common indentation."""
+    offset: int
+    """The line of the docstring on which the example starts, counting from zero."""
+    check: bool = True
+    """Whether the syntax of the example is checked, i.e. it has no `% no-check` comment."""
+
+
+def _indent(line: str) -> int:
+    return len(line) - len(line.lstrip())
+
+
+def _example(lines: list[str], offset: int) -> Example:
+    """
+    Create an example from consecutive lines of a docstring.
+
+    Args:
+        lines: The lines of the example, without prompts.
+        offset: The line of the docstring on which the example starts.
+
+    Returns:
+        The example.
+    """
+    check = not any(NO_CHECK.search(line) for line in lines)
+    return Example(textwrap.dedent("\n".join(lines)), offset, check)
+
+
+def extract_examples(docstring: Docstring | str) -> list[Example]:
+    """
+    Extract the code examples of a docstring.
+
+    Examples are either the indented lines following an `Example:` or `Examples:` header, or
+    consecutive lines starting with the `>>` prompt. Lines following a prompt that do not start
+    with one are considered output and left out.
+
+    Args:
+        docstring: The docstring, or its text.
+
+    Returns:
+        The examples, in order of appearance.
+    """
+    lines = (docstring.value if isinstance(docstring, Docstring) else docstring).splitlines()
+    examples: list[Example] = []
+    index = 0
+    while index < len(lines):
+        line = lines[index]
+        if line.lstrip().startswith(PROMPT):
+            start = index
+            while index < len(lines) and lines[index].lstrip().startswith(PROMPT):
+                index += 1
+            code = [line.lstrip()[len(PROMPT) :] for line in lines[start:index]]
+            examples.append(_example(code, start))
+            continue
+
+        index += 1
+        if EXAMPLE_HEADER.match(line) is None:
+            continue
+        start = index
+        while index < len(lines) and (
+            not lines[index].strip() or _indent(lines[index]) > _indent(line)
+        ):
+            index += 1
+        block = lines[start:index]
+        if any(item.lstrip().startswith(PROMPT) for item in block):
+            # Examples with prompts are extracted line by line, leaving out their output.
+            index = start
+            continue
+        while block and not block[-1].strip():
+            block.pop()
+        while block and not block[0].strip():
+            block.pop(0)
+            start += 1
+        if block:
+            examples.append(_example(block, start))
+    return examples
+
+
+def check_examples(docstring: Docstring) -> list[ParseIssue]:
+    """
+    Check the syntax of the code examples of a docstring.
+
+    Args:
+        docstring: The docstring.
+
+    Returns:
+        An issue for each syntax error, located at its line in the file. If the line of the
+        docstring is unknown, the issues are located relative to the start of the docstring.
+    """
+    issues: list[ParseIssue] = []
+    first_line = docstring.lineno if docstring.lineno is not None else 1
+    for example in extract_examples(docstring):
+        if not example.check:
+            continue
+        file = FileParser(Path("example.m"), source=example.code.encode("utf-8"))
+        for issue in file.parse_tolerant().errors:
+            if issue.kind is not ParseIssueKind.syntax:
+                continue
+            lineno = issue.lineno
+            if lineno is not None:
+                lineno += first_line + example.offset - 1
+            issues.append(ParseIssue(issue.kind, f"In example: {issue.message}", lineno))
+    return issues
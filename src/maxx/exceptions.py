@@ -4,7 +4,30 @@ from __future__ import annotations
 
 
 class MaltError(Exception):
-    """The base exception for all Malt errors."""
+    """The base exception for all Malt errors.
+
+    When raised from another exception, the message of the underlying cause is appended,
+    so that the full chain is visible without inspecting the traceback.
+    """
+
+    def __str__(self) -> str:
+        message = super().__str__()
+        if self.__cause__ is not None:
+            return f"{message}: {self.__cause__}" if message else str(self.__cause__)
+        return message
+
+    def with_context(self, context: str) -> MaltError:
+        """Wrap this error with additional context.
+
+        Parameters:
+            context: A description of what was being done when the error occurred.
+
+        Returns:
+            A new error with the context as message and this error as its cause.
+        """
+        error = MaltError(context)
+        error.__cause__ = self
+        return error
 
 
 class CyclicAliasError(MaltError):
@@ -28,3 +51,42 @@ class FilePathError(MaltError):
 
 class NameResolutionError(MaltError):
     """Exception for names that cannot be resolved in a object scope."""
+
+
+class AmbiguousNameError(NameResolutionError):
+    """Exception raised when a name matches several names that differ only by case."""
+
+    def __init__(self, name: str, candidates: list[str]) -> None:
+        """Initialize the exception.
+
+        Parameters:
+            name: The name that was looked up.
+            candidates: The names that match it case-insensitively.
+        """
+        self.name: str = name
+        """The name that was looked up."""
+        self.candidates: list[str] = candidates
+        """The names that match it case-insensitively."""
+
+        super().__init__(f"Ambiguous name {name!r}, matches: {', '.join(candidates)}")
+
+
+class ArgumentOrderError(MaltError):
+    """Exception raised when the arguments of a function are declared in an invalid order."""
+
+    def __init__(self, argument: str, kind: str, expected: str) -> None:
+        """Initialize the exception.
+
+        Parameters:
+            argument: The name of the offending argument.
+            kind: The kind of the argument.
+            expected: The kinds the argument was expected to have at its position.
+        """
+        self.argument: str = argument
+        """The name of the offending argument."""
+        self.kind: str = kind
+        """The kind of the argument."""
+        self.expected: str = expected
+        """The kinds the argument was expected to have at its position."""
+
+        super().__init__(f"Argument {argument!r} is {kind}, expected {expected}")
@@ -1,36 +1,520 @@
 from __future__ import annotations
 
 import json
+import os
+import re
+import sys
 from dataclasses import dataclass
 from pathlib import Path
-from typing import Iterator
+from typing import TYPE_CHECKING, ClassVar, Iterator, Mapping, Union
 
 from tree_sitter import Node
 
-__all__ = ["Expr", "MATLAB_BUILTINS"]
+from maxx.enums import ReferenceKind
+from maxx.exceptions import AmbiguousNameError
+from maxx.mixins import PickleMixin
 
+if TYPE_CHECKING:
+    from maxx.collection import PathsCollection
 
-def _load_matlab_builtins() -> dict:
+__all__ = [
+    "Expr",
+    "Literal",
+    "MATLAB_BUILTINS",
+    "Reference",
+    "Validator",
+    "get_builtins",
+    "load_builtins",
+    "parse_validators",
+    "resolve_name",
+    "set_builtins",
+]
+
+
+MATLAB_BUILTINS_ENV = "MAXX_MATLAB_BUILTINS"
+"""Environment variable pointing to a JSON file that replaces the shipped builtins database."""
+
+
+def _read_matlab_builtins(json_path: Path) -> dict[str, str]:
     """
-    Load the MATLAB builtin functions from a JSON file.
+    Read a mapping of MATLAB builtin names to documentation paths from a JSON file.
+
+    Args:
+        json_path: The path to the JSON file.
 
     Returns:
         dict: A dictionary containing MATLAB builtin functions and their documentation URLs.
+
+    Raises:
+        ValueError: If the file does not contain a mapping of strings to strings.
     """
-    json_path: Path = Path(__file__).parent / "matlab_builtins.json"
     with open(json_path, "r") as file:
-        return json.load(file)
+        builtins = json.load(file)
+    _validate_builtins(builtins, source=str(json_path))
+    return builtins
+
+
+def _validate_builtins(builtins: object, source: str = "builtins") -> None:
+    if not isinstance(builtins, Mapping):
+        raise ValueError(f"{source} must be a mapping of builtin names to documentation paths")
+    for name, url in builtins.items():
+        if not isinstance(name, str) or not isinstance(url, str):
+            raise ValueError(f"{source} contains a non-string entry: {name!r}: {url!r}")
 
 
-MATLAB_BUILTINS: dict[str, str] = _load_matlab_builtins()
+def _load_matlab_builtins() -> dict[str, str]:
+    """
+    Load the MATLAB builtin functions from a JSON file.
+
+    The shipped database is used, unless the `MAXX_MATLAB_BUILTINS` environment variable
+    points to another JSON file.
+
+    Returns:
+        dict: A dictionary containing MATLAB builtin functions and their documentation URLs.
+
+    Raises:
+        ValueError: If the file that the environment variable points to cannot be read or does
+            not contain a mapping of strings to strings.
+    """
+    override = os.environ.get(MATLAB_BUILTINS_ENV)
+    if not override:
+        return _read_matlab_builtins(Path(__file__).parent / "matlab_builtins.json")
+    try:
+        return _read_matlab_builtins(Path(override))
+    except (OSError, ValueError) as error:
+        raise ValueError(f"Invalid {MATLAB_BUILTINS_ENV}={override!r}: {error}") from error
+
+
+_MATLAB_BUILTINS: dict[str, str] = {}
+_builtins_loaded = False
 MATHWORKS_DOC_URL = "https://www.mathworks.com/help/matlab"
 
 
+def get_builtins() -> dict[str, str]:
+    """
+    Return the MATLAB builtins database used to resolve documentation URLs.
+
+    The database is loaded on first use, so that the `MAXX_MATLAB_BUILTINS` environment variable
+    may be set after importing maxx.
+
+    Returns:
+        dict: A dictionary mapping builtin names to documentation paths.
+
+    Raises:
+        ValueError: If the file that `MAXX_MATLAB_BUILTINS` points to is invalid.
+    """
+    if not _builtins_loaded:
+        set_builtins(_load_matlab_builtins())
+    return _MATLAB_BUILTINS
+
+
+if TYPE_CHECKING:
+    MATLAB_BUILTINS: dict[str, str]
+    """The MATLAB builtins database, loaded on first access, see `get_builtins`."""
+
+
+def __getattr__(name: str) -> dict[str, str]:
+    # The builtins database is loaded when `MATLAB_BUILTINS` is first accessed.
+    if name == "MATLAB_BUILTINS":
+        return get_builtins()
+    raise AttributeError(f"module {__name__!r} has no attribute {name!r}")
+
+
+def set_builtins(builtins: Mapping[str, str]) -> None:
+    """
+    Replace the MATLAB builtins database used to resolve documentation URLs.
+
+    Documentation paths are relative to the MathWorks documentation, e.g. `"ref/zeros.html"`.
+
+    Args:
+        builtins: A mapping of builtin names to documentation paths.
+
+    Raises:
+        ValueError: If the mapping contains entries that are not strings.
+    """
+    global _builtins_loaded
+    _validate_builtins(builtins)
+    _MATLAB_BUILTINS.clear()
+    _MATLAB_BUILTINS.update(builtins)
+    _builtins_loaded = True
+
+
+def load_builtins(path: str | Path) -> None:
+    """
+    Replace the MATLAB builtins database with the contents of a JSON file.
+
+    Args:
+        path: The path to a JSON file mapping builtin names to documentation paths.
+
+    Raises:
+        FileNotFoundError: If the file does not exist.
+        ValueError: If the file does not contain a mapping of strings to strings.
+    """
+    set_builtins(_read_matlab_builtins(Path(path)))
+
+
+MATLAB_KEYWORDS = frozenset(
+    {
+        "break",
+        "case",
+        "catch",
+        "classdef",
+        "continue",
+        "else",
+        "elseif",
+        "end",
+        "for",
+        "function",
+        "global",
+        "if",
+        "otherwise",
+        "parfor",
+        "persistent",
+        "return",
+        "spmd",
+        "switch",
+        "try",
+        "while",
+    }
+)
+"""Reserved MATLAB keywords, which are never identifiers."""
+
+ELEMENTWISE_OPS = ("*", "/", "\\", "^", "'")
+"""Characters that follow a dot in element-wise operators such as `.*` and `.'`."""
+
+
+def _is_identifier_start(char: str) -> bool:
+    return char.isascii() and char.isalpha()
+
+
+def _is_identifier_char(char: str) -> bool:
+    return char.isascii() and (char.isalnum() or char == "_")
+
+
+def _scan(text: str) -> Iterator[tuple[str, int, int]]:
+    """
+    Split MATLAB code into tokens, telling character arrays apart from transpose operators.
+
+    A single quote directly following an identifier, number, closing bracket, dot or another quote
+    is a transpose operator instead of the start of a character array. Within square and curly
+    brackets, whitespace separates elements, so a single quote following whitespace starts one.
+
+    Args:
+        text: The MATLAB code.
+
+    Yields:
+        The kind, start and end index of each token. The kind is `"comment"` for comments and line
+            continuations, `"string"` for string and character array literals, `"unterminated"`
+            for literals that are not closed on their line, `"number"`, `"identifier"` for (dotted)
+            identifiers, or `"symbol"` for any other character. Whitespace is skipped.
+    """
+    index = 0
+    length = len(text)
+    # Whether a single quote at the current position would be a transpose operator.
+    transpose = False
+    # The brackets enclosing the current position, innermost last.
+    brackets: list[str] = []
+    while index < length:
+        char = text[index]
+        start = index
+        if char.isspace():
+            if brackets and brackets[-1] in "[{":
+                transpose = False
+            index += 1
+        elif char == "%" or text.startswith("...", index):
+            # Comments and line continuations run until the end of the line.
+            newline = text.find("\n", index)
+            index = length if newline == -1 else newline
+            transpose = False
+            yield "comment", start, index
+        elif char == '"' or (char == "'" and not transpose):
+            # String or character array literal, where quotes are escaped by doubling them.
+            index += 1
+            kind = "unterminated"
+            while index < length and text[index] != "\n":
+                if text[index] == char:
+                    if text[index + 1 : index + 2] != char:
+                        index += 1
+                        kind = "string"
+                        break
+                    index += 1
+                index += 1
+            transpose = False
+            yield kind, start, index
+        elif char.isdigit() or (char == "." and text[index + 1 : index + 2].isdigit()):
+            # Numeric literal, including exponents, hexadecimal and type suffixes.
+            hexadecimal = text[index : index + 2].lower() in ("0x", "0b")
+            index += 1
+            while index < length:
+                if _is_identifier_char(text[index]):
+                    index += 1
+                elif text[index] == "." and text[index + 1 : index + 2] not in ELEMENTWISE_OPS:
+                    index += 1
+                elif text[index] in "+-" and text[index - 1] in "eE" and not hexadecimal:
+                    index += 1
+                else:
+                    break
+            transpose = True
+            yield "number", start, index
+        elif _is_identifier_start(char):
+            while index < length:
+                while index < length and _is_identifier_char(text[index]):
+                    index += 1
+                if (
+                    index + 1 < length
+                    and text[index] == "."
+                    and _is_identifier_start(text[index + 1])
+                ):
+                    index += 1
+                    continue
+                break
+            transpose = True
+            yield "identifier", start, index
+        else:
+            if char in "([{":
+                brackets.append(char)
+            elif char in ")]}" and brackets:
+                brackets.pop()
+            transpose = char in ")]}.'"
+            index += 1
+            yield "symbol", start, index
+
+
+def _tokenize_identifiers(text: str) -> Iterator[str]:
+    """
+    Extract the (dotted) identifiers referenced in a MATLAB expression.
+
+    String and character array literals, comments and numeric literals are skipped.
+
+    Args:
+        text: The MATLAB expression.
+
+    Yields:
+        str: The identifiers, in order of appearance.
+    """
+    # Whether an identifier at the current position would be a field name.
+    field = False
+    for kind, start, end in _scan(text):
+        if kind == "identifier":
+            identifier = text[start:end]
+            if not field and identifier not in MATLAB_KEYWORDS:
+                yield identifier
+        # Identifiers following a dot, e.g. `x(1).field`, are field names.
+        field = kind == "symbol" and text[start] == "."
+
+
+Literal = Union[int, float, str, bool, list["Literal"]]
+"""A MATLAB literal value, mapped onto the corresponding Python type."""
+
+_INTEGER = re.compile(r"[+-]?\d+")
+_NUMBER = re.compile(r"[+-]?(\d+\.?\d*|\.\d+)([eE][+-]?\d+)?")
+_BOOLEANS = {"true": True, "false": False}
+
+
+def _parse_scalar(text: str) -> Literal | None:
+    """
+    Evaluate a scalar MATLAB literal.
+
+    Args:
+        text: The literal, without surrounding whitespace.
+
+    Returns:
+        The value of the literal, or None if the text is not a scalar literal.
+    """
+    if text in _BOOLEANS:
+        return _BOOLEANS[text]
+    if _INTEGER.fullmatch(text):
+        return int(text)
+    if _NUMBER.fullmatch(text):
+        return float(text)
+    for quote in ('"', "'"):
+        if len(text) >= 2 and text[0] == quote and text[-1] == quote:
+            content = text[1:-1]
+            if content.replace(quote * 2, "").count(quote):
+                return None
+            return content.replace(quote * 2, quote)
+    return None
+
+
+def _parse_array_element(text: str) -> Literal | None:
+    """
+    Evaluate an element of a bracketed MATLAB array.
+
+    Character arrays are excluded, since bracketing them concatenates rather than lists them.
+
+    Args:
+        text: The element, without surrounding whitespace.
+
+    Returns:
+        The value of the element, or None if it is not a literal.
+    """
+    if text.startswith("'"):
+        return None
+    return _parse_scalar(text)
+
+
+def _parse_literal(text: str) -> Literal | None:
+    """
+    Evaluate a MATLAB literal into a Python value.
+
+    Only numbers (including negative ones), character arrays, strings, logicals and bracketed
+    arrays of those are evaluated. Arrays with multiple rows are returned as a list of rows.
+    Anything containing identifiers, operators or function calls is not a literal.
+
+    Args:
+        text: The MATLAB expression.
+
+    Returns:
+        The value of the literal, `[]` for the empty array, or None if the text is not a literal.
+    """
+    text = text.strip()
+    if not (text.startswith("[") and text.endswith("]")):
+        return _parse_scalar(text)
+
+    content = text[1:-1].strip()
+    if not content:
+        return []
+
+    rows: list[Literal] = []
+    for row in content.split(";"):
+        values: list[Literal] = []
+        for part in row.split(","):
+            elements = part.split()
+            if not elements:
+                # Empty elements, such as in `[1,,2]`.
+                return None
+            for element in elements:
+                value = _parse_array_element(element)
+                if value is None:
+                    return None
+                values.append(value)
+        rows.append(values)
+    return rows[0] if len(rows) == 1 else rows
+
+
+@dataclass
+class Reference:
+    """An identifier referenced in an expression or function, and what it resolves to."""
+
+    name: str
+    """The (dotted) identifier as written in the expression."""
+    kind: ReferenceKind
+    """Whether the identifier is a variable, a function or object, a builtin, or unknown."""
+    url: str = ""
+    """The documentation URL, for builtins."""
+    path: str = ""
+    """The canonical path of the object, for local and private functions and collected objects."""
+
+
+@dataclass(frozen=True)
+class Validator:
+    """A validation function of an argument or property, e.g. `mustBeMember(x, ["a", "b"])`."""
+
+    name: str
+    """The (dotted) name of the function, or the raw text of a validator list that is malformed."""
+    arguments: str | None = None
+    """The raw text between the parentheses, or None if the function is not called with any."""
+    raw: bool = False
+    """Whether the validator list could not be parsed, in which case the name is its raw text."""
+
+    def __str__(self) -> str:
+        return self.name if self.arguments is None else f"{self.name}({self.arguments})"
+
+    @property
+    def doc(self) -> str:
+        """The documentation URL of the function if it is a MATLAB builtin."""
+        return "" if self.raw else _builtin_doc_url(self.name)
+
+
+_VALIDATOR = re.compile(r"([A-Za-z]\w*(?:\.[A-Za-z]\w*)*)\s*(?:\((.*)\))?", re.DOTALL)
+_CONTINUATION = re.compile(r"\.\.\..*")
+
+
+def _split_top_level(text: str) -> list[str] | None:
+    """
+    Split MATLAB code on the commas that are not nested in brackets or string literals.
+
+    Args:
+        text: The code to split.
+
+    Returns:
+        The parts between the commas, or None if the brackets or quotes are unbalanced.
+    """
+    parts: list[str] = []
+    depth = 0
+    start = 0
+    for kind, index, _ in _scan(text):
+        if kind == "unterminated":
+            return None
+        if kind != "symbol":
+            continue
+        char = text[index]
+        if char in "([{":
+            depth += 1
+        elif char in ")]}":
+            depth -= 1
+            if depth < 0:
+                return None
+        elif char == "," and depth == 0:
+            parts.append(text[start:index])
+            start = index + 1
+    if depth:
+        return None
+    parts.append(text[start:])
+    return parts
+
+
+def parse_validators(text: str) -> list[Validator]:
+    """
+    Parse the validation functions of an argument or property.
+
+    Args:
+        text: The validator list, with or without its braces, e.g. `{mustBePositive, mustBeReal}`.
+
+    Returns:
+        The validators in order. A malformed list gives a single validator with its raw text.
+    """
+    text = text.strip()
+    content = text[1:-1] if text.startswith("{") and text.endswith("}") else text
+    content = _CONTINUATION.sub("", content).strip()
+    if not content:
+        return []
+
+    validators: list[Validator] = []
+    for entry in _split_top_level(content) or [""]:
+        match = _VALIDATOR.fullmatch(entry.strip())
+        if (
+            match is None
+            or match.group(1) in MATLAB_KEYWORDS
+            or (match.group(2) is not None and _split_top_level(match.group(2)) is None)
+        ):
+            return [Validator(text, raw=True)]
+        name, arguments = match.groups()
+        if arguments is not None:
+            arguments = arguments.strip()
+        validators.append(Validator(sys.intern(name), arguments))
+    return validators
+
+
+@dataclass(frozen=True)
+class _TextNode:
+    """A stand-in for a tree-sitter node that only keeps its text, used when pickling."""
+
+    text: bytes | None
+
+
 @dataclass
-class Expr:
+class Expr(PickleMixin):
     nodes: list[Node]
     encoding: str
 
+    _shared: ClassVar[tuple[str, ...]] = ("nodes",)
+
+    def __getstate__(self) -> dict:
+        state = super().__getstate__()
+        state["nodes"] = [_TextNode(node.text) for node in self.nodes]
+        return state
+
     def iterate(self) -> Iterator[str]:
         """Iterate over the values of the expression."""
         for node in self.nodes:
@@ -46,8 +530,134 @@ class Expr:
         yield from self.iterate()
 
     @property
-    def doc(self) -> str:
+    def identifiers(self) -> list[str]:
+        """The unique (dotted) identifiers referenced in the expression, in order of appearance.
+
+        String literals, comments and numbers are ignored.
+        """
+        identifiers: dict[str, None] = {}
         for elem in self.iterate():
-            if elem in MATLAB_BUILTINS:
-                return f"{MATHWORKS_DOC_URL}/{MATLAB_BUILTINS[elem]}"
-        return ""
+            for identifier in _tokenize_identifiers(elem):
+                identifiers[identifier] = None
+        return list(identifiers)
+
+    @property
+    def doc_urls(self) -> list[str]:
+        """The documentation URLs of all MATLAB builtins referenced in the expression."""
+        urls: dict[str, None] = {}
+        for identifier in self.identifiers:
+            url = _builtin_doc_url(identifier)
+            if url:
+                urls[url] = None
+        return list(urls)
+
+    @property
+    def doc(self) -> str:
+        """The documentation URL of the first MATLAB builtin referenced in the expression."""
+        return next(iter(self.doc_urls), "")
+
+    @property
+    def literal(self) -> Literal | None:
+        """The value of the expression if it is a literal, mapped onto the Python type.
+
+        See [`Literal`][maxx.expressions.Literal]. The empty array `[]` evaluates to an empty list,
+        whereas None is returned for anything that is not a literal, such as function calls.
+        """
+        return _parse_literal(str(self))
+
+    @property
+    def is_string_literal(self) -> bool:
+        """Whether the expression is a single character array `'...'` or string `"..."`."""
+        return isinstance(self.literal, str)
+
+    @property
+    def string_value(self) -> str | None:
+        """The value of a character array or string literal, with doubled quotes unescaped."""
+        literal = self.literal
+        return literal if isinstance(literal, str) else None
+
+    @property
+    def is_numeric_literal(self) -> bool:
+        """Whether the expression is a single number, such as `3`, `-0.5` or `1e-3`."""
+        return self.numeric_value is not None
+
+    @property
+    def numeric_value(self) -> float | None:
+        """The value of a numeric literal as a float. Logicals are not numeric literals."""
+        literal = self.literal
+        if isinstance(literal, bool) or not isinstance(literal, (int, float)):
+            return None
+        return float(literal)
+
+    def resolve(self, paths_collection: "PathsCollection | None" = None) -> list[Reference]:
+        """Resolve the identifiers referenced in the expression.
+
+        Objects in the paths collection shadow MATLAB builtins of the same name.
+        For dotted names, the full name is tried first and then its leading identifier.
+
+        Parameters:
+            paths_collection: The collection used to look up project objects.
+
+        Returns:
+            A reference for each identifier, in order of appearance.
+        """
+        references: list[Reference] = []
+        for identifier in self.identifiers:
+            candidates = resolve_name(identifier, paths_collection)
+            references.append(
+                candidates[0] if candidates else Reference(identifier, ReferenceKind.unknown)
+            )
+        return references
+
+
+def resolve_name(
+    identifier: str, paths_collection: "PathsCollection | None" = None
+) -> list[Reference]:
+    """
+    Resolve an identifier to the collected object and the MATLAB builtin it can refer to.
+
+    For dotted names, the full name is tried first and then its leading identifier.
+
+    Args:
+        identifier: The (dotted) identifier.
+        paths_collection: The collection used to look up project objects.
+
+    Returns:
+        list[Reference]: The collected object, which shadows the builtin, followed by the builtin,
+            each only if found.
+    """
+    references: list[Reference] = []
+    if paths_collection is not None:
+        for name in (identifier, identifier.split(".")[0]):
+            try:
+                object = paths_collection.get_member(name)
+            except (KeyError, AmbiguousNameError):
+                object = None
+            if object is not None:
+                references.append(
+                    Reference(identifier, ReferenceKind.object, path=object.canonical_path)
+                )
+                break
+    url = _builtin_doc_url(identifier)
+    if url:
+        references.append(Reference(identifier, ReferenceKind.builtin, url=url))
+    return references
+
+
+def _builtin_doc_url(identifier: str) -> str:
+    """
+    Return the documentation URL of an identifier if it refers to a MATLAB builtin.
+
+    For dotted names, the full name is tried first and then its leading identifier.
+
+    Args:
+        identifier: The (dotted) identifier.
+
+    Returns:
+        str: The documentation URL, or an empty string if the identifier is not a builtin.
+    """
+    builtins = get_builtins()
+    for name in (identifier, identifier.split(".")[0]):
+        if name in builtins:
+            return f"{MATHWORKS_DOC_URL}/{builtins[name]}"
+    return ""
@@ -2,10 +2,12 @@
 
 from __future__ import annotations
 
+from copy import deepcopy
 from pathlib import Path
-from typing import TYPE_CHECKING, Any, Sequence, cast
+from typing import TYPE_CHECKING, Any, Sequence, TypeVar, cast
 
 from maxx.enums import Kind
+from maxx.exceptions import MaltError
 
 if TYPE_CHECKING:
     from maxx.objects import (
@@ -21,6 +23,12 @@ if TYPE_CHECKING:
     )
 
 
+_T = TypeVar("_T", bound="PickleMixin")
+
+PICKLE_VERSION = 1
+"""The version of the pickled state, bumped whenever it changes incompatibly."""
+
+
 def _get_parts(key: str | Sequence[str]) -> Sequence[str]:
     if isinstance(key, str):
         if not key:
@@ -177,6 +185,57 @@ class SetMembersMixin:
             self.members[parts[0]].set_member(parts[1:], value)
 
 
+class PickleMixin:
+    """Mixin class to support pickling and copying objects that hold tree-sitter nodes.
+
+    Tree-sitter nodes cannot be pickled, so they are dropped from the pickled state.
+    The state is tagged with [`PICKLE_VERSION`][maxx.mixins.PICKLE_VERSION], so that
+    state pickled by an incompatible version of maxx raises a clear error.
+
+    Copies keep their nodes. Deep copies share the nodes, parent and paths collection with the
+    original, unless the parent is copied along, so that a parsed object can be modified without
+    affecting the original or copying the whole collection it belongs to.
+    """
+
+    _unpicklable: tuple[str, ...] = ("node",)
+    """The attributes that are set to None in the pickled state."""
+    _shared: tuple[str, ...] = ("node", "parent", "_paths_collection")
+    """The attributes that deep copies share with the original."""
+
+    def __copy__(self: _T) -> _T:
+        copy = self.__class__.__new__(self.__class__)
+        copy.__dict__.update(self.__dict__)
+        return copy
+
+    def __deepcopy__(self: _T, memo: dict[int, Any]) -> _T:
+        copy = self.__class__.__new__(self.__class__)
+        memo[id(self)] = copy
+        for name, value in self.__dict__.items():
+            if name in self._shared:
+                # Refer to the copy if the attribute is copied along, e.g. a copied parent class.
+                copy.__dict__[name] = memo.get(id(value), value)
+            else:
+                copy.__dict__[name] = deepcopy(value, memo)
+        return copy
+
+    def __getstate__(self) -> dict[str, Any]:
+        state = self.__dict__.copy()
+        for name in self._unpicklable:
+            if name in state:
+                state[name] = None
+        state["__pickle_version__"] = PICKLE_VERSION
+        return state
+
+    def __setstate__(self, state: dict[str, Any]) -> None:
+        version = state.pop("__pickle_version__", None)
+        if version != PICKLE_VERSION:
+            raise MaltError(
+                f"Cannot unpickle {self.__class__.__name__}: pickled with state version {version}, "
+                f"expected {PICKLE_VERSION}"
+            )
+        self.__dict__.update(state)
+
+
 class PathMixin:
     """
     A mixin class that provides a filepath attribute and related functionality.
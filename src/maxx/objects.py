@@ -1,51 +1,62 @@
 from __future__ import annotations
 
+import re
+import sys
 import threading
 from contextlib import suppress
 from pathlib import Path
 from textwrap import dedent
-from typing import TYPE_CHECKING, Any, Callable, NoReturn, Sequence, cast
+from typing import TYPE_CHECKING, Any, Callable, Iterator, NoReturn, Sequence, cast
 
 from griffe import Docstring
 from griffe._internal.c3linear import c3linear_merge
 from loguru import logger
 from tree_sitter import Node
 
-from maxx.enums import AccessKind, ArgumentKind, Kind
-from maxx.exceptions import CyclicAliasError, FilePathError, NameResolutionError
-from maxx.expressions import Expr
-from maxx.mixins import ObjectAliasMixin, PathMixin
+from maxx.enums import AccessKind, ArgumentKind, Kind, TestRole
+from maxx.exceptions import (
+    ArgumentOrderError,
+    CyclicAliasError,
+    FilePathError,
+    NameResolutionError,
+)
+from maxx.expressions import Expr, Validator
+from maxx.mixins import ObjectAliasMixin, PathMixin, PickleMixin
 
 if TYPE_CHECKING:
     from maxx.collection import LinesCollection, PathsCollection
 
 
-class Validatable:
+class Validatable(PickleMixin):
     """This class represent a Validable (argument / property)."""
 
+    _shared = (*PickleMixin._shared, "function")
+
     def __init__(
         self,
         name: str,
         *,
         type: Expr | str | None = None,
-        dimensions: list[str] | None = None,
+        dimensions: list[int | None] | None = None,
         default: Expr | str | None = None,
         docstring: Docstring | None = None,
-        validators: Expr | str | None = None,
+        validators: list[Validator] | None = None,
         parent: Object | None = None,
         node: Node | None = None,
+        lineno: int | None = None,
+        endlineno: int | None = None,
         paths_collection: "PathsCollection | None" = None,
         **kwargs: Any,
     ) -> None:
         """Initialize the validatable."""
-        self.name: str = name
+        self.name: str = sys.intern(name)
         """The validatable name."""
         self.type: Expr | str | None = type
         """The validatable type type."""
-        self.dimensions: list[str] | None = dimensions
-        """The validatable dimensions, if any."""
-        self.validators: Expr | str | None = validators
-        """The validatable validators, if any."""
+        self.dimensions: list[int | None] | None = dimensions
+        """The validatable dimensions, if any, with `None` for dimensions of any size (`:`)."""
+        self.validators: list[Validator] = validators if validators is not None else []
+        """The validation functions of the validatable, in order."""
         self.default: Expr | str | None = default
         """The validatable default value."""
         self.docstring: Docstring | None = docstring
@@ -54,6 +65,10 @@ class Validatable:
         """The parent of the validatable (none if top module)."""
         self.node: Node | None = node
         """The tree-sitter node of the object."""
+        self.lineno: int | None = lineno
+        """The starting line of the declaration, or None if unknown. Lines start at 1."""
+        self.endlineno: int | None = endlineno
+        """The ending line of the declaration (inclusive)."""
         self._paths_collection: "PathsCollection | None" = paths_collection
 
         # Attach the docstring to this object.
@@ -65,6 +80,28 @@ class Validatable:
         """Whether this object has a docstring (empty or not)."""
         return bool(self.docstring)
 
+    @property
+    def is_scalar(self) -> bool:
+        """Whether the dimensions restrict the validatable to a scalar, i.e. `(1,1)`."""
+        return self.dimensions == [1, 1]
+
+    @property
+    def declaration(self) -> str:
+        """The declaration in MATLAB syntax, e.g. `x (1,:) double {mustBeNumeric} = 0`."""
+        parts = [self.name]
+        if self.dimensions is not None:
+            sizes = ",".join(":" if size is None else str(size) for size in self.dimensions)
+            parts.append(f"({sizes})")
+        if self.type is not None:
+            parts.append(str(self.type))
+        if self.validators:
+            validators = ", ".join(str(validator) for validator in self.validators)
+            # The raw text of a malformed validator list may include its braces.
+            parts.append(validators if validators.startswith("{") else f"{{{validators}}}")
+        if self.default is not None:
+            parts.append(f"= {self.default}")
+        return " ".join(parts)
+
     def __str__(self) -> str:
         arg = f"{self.name}: {self.type} = {self.default}"
         if hasattr(self, "kind") and self.kind is not None:
@@ -75,11 +112,22 @@ class Validatable:
 class Argument(Validatable):
     """This class represent a function argument."""
 
-    def __init__(self, *args, kind: ArgumentKind | None = None, **kwargs) -> None:
+    def __init__(
+        self,
+        *args,
+        kind: ArgumentKind | None = None,
+        repeating: bool = False,
+        name_value_struct: str | None = None,
+        **kwargs,
+    ) -> None:
         """Initialize the argument."""
         super().__init__(*args, **kwargs)
         self.kind = kind
         """The argument kind."""
+        self.repeating: bool = repeating
+        """Whether the argument is declared in an `arguments (Repeating)` block."""
+        self.name_value_struct: str | None = name_value_struct
+        """The name of the structure of a name-value argument, e.g. `options` in `options.Name`."""
 
     def __repr__(self) -> str:
         return f"Argument(name={self.name!r}, type={self.type!r}, kind={self.kind!r}, default={self.default!r})"
@@ -89,6 +137,19 @@ class Argument(Validatable):
         """Whether this argument is required."""
         return self.default is None
 
+    @property
+    def declaration(self) -> str:
+        """The declaration in MATLAB syntax, prefixed by the structure of a name-value argument."""
+        declaration = super().declaration
+        if self.name_value_struct is None:
+            return declaration
+        return f"{self.name_value_struct}.{declaration}"
+
+    @property
+    def is_name_value(self) -> bool:
+        """Whether this argument is a name-value argument, declared as `options.Name`."""
+        return self.kind is ArgumentKind.keyword_only
+
     def __eq__(self, value: object, /) -> bool:
         """Arguments are equal if all their attributes except `docstring` and `function` are equal."""
         if not isinstance(value, Argument):
@@ -98,6 +159,8 @@ class Argument(Validatable):
             and self.type == value.type
             and self.kind == value.kind
             and self.default == value.default
+            and self.repeating == value.repeating
+            and self.name_value_struct == value.name_value_struct
         )
 
 
@@ -189,7 +252,7 @@ class Arguments:
         self._args.append(argument)
 
 
-class Object(ObjectAliasMixin):
+class Object(ObjectAliasMixin, PickleMixin):
     """An abstract class representing a Python object."""
 
     kind: Kind
@@ -203,6 +266,8 @@ class Object(ObjectAliasMixin):
 
     Only aliases can be marked as inherited.
     """
+    Access: AccessKind = AccessKind.public
+    """The declared access of the object, which only methods and properties restrict."""
 
     def __init__(
         self,
@@ -228,7 +293,7 @@ class Object(ObjectAliasMixin):
             lines_collection: A collection of source code lines.
             paths_collection: A collection of path objects.
         """
-        self.name: str = name
+        self.name: str = sys.intern(name)
         """The object name."""
 
         self.lineno: int | None = lineno
@@ -286,11 +351,49 @@ class Object(ObjectAliasMixin):
         """Whether this object is private."""
         return False
 
+    @property
+    def is_public(self) -> bool:
+        """Whether this object is public.
+
+        An explicitly set [`public`][maxx.objects.Object.public] takes precedence, otherwise
+        objects are public unless they are private.
+        """
+        if self.public is not None:
+            return self.public
+        return not self.is_private
+
+    def effective_access(self, parent_access: AccessKind | None = None) -> AccessKind:
+        """The access of this object, restricted by the access of the block declaring it.
+
+        Parameters:
+            parent_access: The access of the enclosing block, e.g. of a `methods` block.
+
+        Returns:
+            The most restrictive of the access of the object and of the block. Immutable access
+            only restricts setting values, and is as open as public access.
+        """
+        if self.public is False:
+            return AccessKind.private
+        access = AccessKind.public if self.public else self.Access
+        restriction = {AccessKind.protected: 1, AccessKind.private: 2}
+        return max(
+            (access, parent_access or AccessKind.public),
+            key=lambda kind: restriction.get(kind, 0),
+        )
+
     @property
     def has_docstring(self) -> bool:
         """Whether this object has a docstring (empty or not)."""
         return bool(self.docstring)
 
+    @property
+    def h1_line(self) -> str | None:
+        """The first non-empty line of the docstring, as shown by MATLAB's `lookfor`."""
+        if self.docstring is None:
+            return None
+        lines = (line.strip() for line in self.docstring.value.splitlines())
+        return next((line for line in lines if line), None)
+
     def is_kind(self, kind: str | Kind | set[str | Kind]) -> bool:
         """Tell if this object is of the given kind.
 
@@ -388,6 +491,30 @@ class Object(ObjectAliasMixin):
         }
         return members
 
+    def walk(self) -> Iterator[Object]:
+        """Iterate over this object and all its members, depth-first.
+
+        Aliased members are resolved. Members that are reachable from more than one parent,
+        such as the methods of a class folder, are visited once.
+
+        Yields:
+            This object, followed by each member and its own members.
+        """
+        seen: set[int] = set()
+        stack: list[Object | Alias] = [self]
+        while stack:
+            member = stack.pop()
+            if isinstance(member, Alias):
+                try:
+                    member = member.target
+                except (CyclicAliasError, ValueError):
+                    continue
+            if member is None or id(member) in seen:
+                continue
+            seen.add(id(member))
+            yield member
+            stack.extend(reversed(member.members.values()))
+
     @property
     def namespace(self) -> Namespace:
         """The parent namespace of this object.
@@ -522,7 +649,7 @@ class Object(ObjectAliasMixin):
         return self.parent.resolve(name)
 
 
-class Alias(ObjectAliasMixin):
+class Alias(ObjectAliasMixin, PickleMixin):
     """This class represents an alias, or indirection, to an object declared in another module.
 
     Aliases represent objects that are in the scope of a module or class,
@@ -562,7 +689,7 @@ class Alias(ObjectAliasMixin):
             parent: The alias parent.
             inherited: Whether this alias wraps an inherited member.
         """
-        self.name: str = name
+        self.name: str = sys.intern(name)
         """The alias name."""
 
         self.inherited: bool = inherited
@@ -641,8 +768,37 @@ class Alias(ObjectAliasMixin):
         return self.target.is_private
 
     def __getattr__(self, item):
+        if item.startswith("__") and item.endswith("__"):
+            # Special attributes, e.g. looked up by pickle or copy, must not resolve the target.
+            raise AttributeError(item)
         return getattr(self._actual, item)
 
+    _shared = ("_parent", "_constructor", "_lock")
+
+    def __copy__(self) -> Alias:
+        copy = super().__copy__()
+        if not copy.resolved:
+            copy._lock = threading.Lock()
+        return copy
+
+    def __deepcopy__(self, memo: dict[int, Any]) -> Alias:
+        copy = super().__deepcopy__(memo)
+        if not copy.resolved:
+            copy._lock = threading.Lock()
+        return copy
+
+    def __getstate__(self) -> dict[str, Any]:
+        state = super().__getstate__()
+        state.pop("_lock", None)
+        if self.resolved:
+            state.pop("_constructor", None)
+        return state
+
+    def __setstate__(self, state: dict[str, Any]) -> None:
+        super().__setstate__(state)
+        if not self.resolved:
+            self._lock = threading.Lock()
+
     def __bool__(self) -> bool:
         """An alias is always true-ish."""
         return True
@@ -735,7 +891,176 @@ class Namespace(PathMixin, Object):
         return "+" + self.canonical_path
 
 
-class Class(PathMixin, Object):
+TEST_CASE_CLASSES = (
+    "matlab.unittest.TestCase",
+    "matlab.mock.TestCase",
+    "matlab.perftest.TestCase",
+    "matlab.uitest.TestCase",
+)
+"""The MATLAB classes from which test classes derive."""
+
+
+class InheritanceMixin:
+    """Mixin class to share the inheritance lookups of classes and class folders.
+
+    Methods:
+        mro: The resolvable bases in MATLAB's method resolution order.
+        all_methods: All methods, including inherited ones.
+        all_properties: All properties, including inherited ones.
+        is_test_class: Whether the class derives from a test case class.
+    """
+
+    if TYPE_CHECKING:
+        # Type stubs for attributes that must be provided by subclasses
+        name: str
+        path: str
+        bases: list[str]
+        members: dict[str, Object | Alias]
+        paths_collection: PathsCollection
+
+    @property
+    def resolved_bases(self) -> list[Object]:
+        """The bases of this class that are found in the attached paths collection."""
+        return self._resolve_bases(None)
+
+    def _resolve_bases(self, paths_collection: PathsCollection | None) -> list[Object]:
+        resolved_bases: list[Object] = []
+        if self.bases and paths_collection is None:
+            paths_collection = self.paths_collection
+        for base in self.bases:
+            try:
+                resolved_base = paths_collection.get_member(base)
+                if isinstance(resolved_base, Alias):
+                    resolved_base = resolved_base.target
+            except (CyclicAliasError, KeyError, ValueError):
+                resolved_base = None
+            if resolved_base is None:
+                logger.debug(f"Base class {base} is not loaded, it cannot be resolved")
+            else:
+                resolved_bases.append(resolved_base)
+        return resolved_bases
+
+    def _mro(
+        self, paths_collection: PathsCollection | None = None, seen: tuple[str, ...] = ()
+    ) -> list[Class | ClassFolder]:
+        this = cast("Class | ClassFolder", self)
+        seen = (*seen, self.path)
+        bases: list[Class | ClassFolder] = [
+            base
+            for base in self._resolve_bases(paths_collection)
+            if isinstance(base, (Class, ClassFolder))
+        ]
+        if not bases:
+            return [this]
+        for base in bases:
+            if base.path in seen:
+                cycle: str = " -> ".join(seen) + f" -> {base.path}"
+                raise ValueError(
+                    f"Cannot compute C3 linearization, inheritance cycle detected: {cycle}"
+                )
+        base_mros = [base._mro(paths_collection, seen) for base in bases]
+        return [this, *c3linear_merge(*base_mros, bases)]
+
+    def mro(self, paths_collection: PathsCollection | None = None) -> list[Class | ClassFolder]:
+        """Return a list of classes in order corresponding to MATLAB's MRO.
+
+        Parameters:
+            paths_collection: The collection used to resolve the bases.
+                Defaults to the collection attached to this class.
+
+        Raises:
+            ValueError: When no paths collection is found, or the inheritance is cyclic.
+        """
+        return self._mro(paths_collection)[1:]  # Remove self.
+
+    def _lineage(self, paths_collection: PathsCollection | None) -> list[Class | ClassFolder]:
+        """This class followed by its MRO, or only this class if its bases cannot be resolved."""
+        try:
+            return self._mro(paths_collection)
+        except ValueError as error:
+            logger.debug(f"Skipping the bases of {self.path}: {error}")
+            return [cast("Class | ClassFolder", self)]
+
+    def all_methods(self, paths_collection: PathsCollection | None = None) -> dict[str, Function]:
+        """All methods of this class, including those inherited from its bases.
+
+        Methods defined in a subclass override those of the same name in its bases.
+        The class that defined each method is available as its `parent`.
+
+        Parameters:
+            paths_collection: The collection used to resolve the bases.
+                Defaults to the collection attached to this class.
+
+        Returns:
+            A dictionary mapping method names to functions.
+        """
+        return cast(
+            "dict[str, Function]", self._all_members_of_kind(Kind.FUNCTION, paths_collection)
+        )
+
+    def all_properties(
+        self, paths_collection: PathsCollection | None = None
+    ) -> dict[str, Property]:
+        """All properties of this class, including those inherited from its bases.
+
+        Properties defined in a subclass override those of the same name in its bases.
+        The class that defined each property is available as its `parent`.
+
+        Parameters:
+            paths_collection: The collection used to resolve the bases.
+                Defaults to the collection attached to this class.
+
+        Returns:
+            A dictionary mapping property names to properties.
+        """
+        return cast(
+            "dict[str, Property]", self._all_members_of_kind(Kind.PROPERTY, paths_collection)
+        )
+
+    def is_test_class(self, paths_collection: PathsCollection | None = None) -> bool:
+        """Whether this class is a test class, i.e. it derives from `matlab.unittest.TestCase`.
+
+        Parameters:
+            paths_collection: The collection used to resolve the bases.
+                Defaults to the collection attached to this class.
+
+        Returns:
+            Whether the class or one of its resolvable bases derives from a test case class.
+        """
+        return any(
+            base in TEST_CASE_CLASSES
+            for model in self._lineage(paths_collection)
+            for base in model.bases
+        )
+
+    def _all_members_of_kind(
+        self, kind: Kind, paths_collection: PathsCollection | None
+    ) -> dict[str, Object]:
+        members: dict[str, Object] = {}
+        for model in self._lineage(paths_collection):
+            for name, member in model.members.items():
+                if isinstance(member, Alias):
+                    try:
+                        member = member.target
+                    except (CyclicAliasError, ValueError):
+                        continue
+                if member.kind is not kind or name in members:
+                    continue
+                if model is not self and name == model.name:
+                    # Constructors of base classes are not inherited.
+                    continue
+                members[name] = member
+        return members
+
+
+def _is_public_method(member: Object | Alias | None) -> bool:
+    """Whether a member of a class, possibly an alias, is a public method."""
+    if isinstance(member, Alias):
+        member = member.target
+    return isinstance(member, Function) and member.is_public
+
+
+class Class(InheritanceMixin, PathMixin, Object):
     kind: Kind = Kind.CLASS
 
     def __init__(
@@ -745,6 +1070,7 @@ class Class(PathMixin, Object):
         Abstract: bool = False,
         Hidden: bool = False,
         Sealed: bool = False,
+        local_functions: list[Function] | None = None,
         **kwargs: Any,
     ) -> None:
         """Initialize the class.
@@ -753,6 +1079,7 @@ class Class(PathMixin, Object):
             *args: See [`griffe.Object`][].
             bases: The list of base classes, if any.
             decorators: The class decorators, if any.
+            local_functions: The functions defined after the class definition in its file.
             **kwargs: See [`griffe.Object`][].
         """
         super().__init__(*args, **kwargs)
@@ -760,6 +1087,9 @@ class Class(PathMixin, Object):
         self.bases: list[str] = list(bases) if bases else []
         """The class bases."""
 
+        self.local_functions: list[Function] = local_functions or []
+        """The local functions defined after the `classdef` block, only callable from the file."""
+
         self.Abstract: bool = Abstract
         self.Hidden: bool = Hidden
         self.Sealed: bool = Sealed
@@ -791,39 +1121,6 @@ class Class(PathMixin, Object):
                     return constructor
         return None
 
-    @property
-    def resolved_bases(self) -> list[Object]:
-        resolved_bases: list[Object] = []
-        for base in self.bases:
-            try:
-                resolved_base = self.paths_collection.get_member(base)
-                if isinstance(resolved_base, Alias):
-                    resolved_base = resolved_base.target
-            except (CyclicAliasError, KeyError):
-                logger.debug(
-                    "Base class %s is not loaded, or not static, it cannot be resolved", base
-                )
-            else:
-                resolved_bases.append(resolved_base)
-        return resolved_bases
-
-    def _mro(self, seen: tuple[str, ...] = ()) -> list[Class]:
-        seen = (*seen, self.path)
-        bases: list[Class] = [base for base in self.resolved_bases if isinstance(base, Class)]
-        if not bases:
-            return [self]
-        for base in bases:
-            if base.path in seen:
-                cycle: str = " -> ".join(seen) + f" -> {base.path}"
-                raise ValueError(
-                    f"Cannot compute C3 linearization, inheritance cycle detected: {cycle}"
-                )
-        return [self, *c3linear_merge(*[base._mro(seen) for base in bases], bases)]
-
-    def mro(self) -> list[Class]:
-        """Return a list of classes in order corresponding to MATLAB's MRO."""
-        return self._mro()[1:]  # Remove self.
-
     @property
     def inherited_members(self) -> dict[str, Alias]:
         """Retrieve a dictionary of inherited members from base classes.
@@ -848,8 +1145,53 @@ class Class(PathMixin, Object):
         self._inherited_members = inherited_members
         return inherited_members
 
+    @property
+    def enumeration_values(self) -> list[Enumeration]:
+        """The members declared in the `enumeration` blocks of this class, in order."""
+        return [member for member in self.members.values() if isinstance(member, Enumeration)]
+
+    @property
+    def is_enumeration(self) -> bool:
+        """Whether this class is an enumeration class, i.e. it declares enumeration members."""
+        return bool(self.enumeration_values)
+
+    def getter_for(self, name: str) -> Function | None:
+        """The `get.<name>` method of a property of this class, if any.
+
+        Parameters:
+            name: The property name.
+
+        Returns:
+            The getter, or None if the class has no such property or it has no getter.
+        """
+        prop = self.members.get(name)
+        return prop.getter if isinstance(prop, Property) else None
 
-class ClassFolder(Folder):
+    def setter_for(self, name: str) -> Function | None:
+        """The `set.<name>` method of a property of this class, if any.
+
+        Parameters:
+            name: The property name.
+
+        Returns:
+            The setter, or None if the class has no such property or it has no setter.
+        """
+        prop = self.members.get(name)
+        return prop.setter if isinstance(prop, Property) else None
+
+    def is_method_public(self, name: str) -> bool:
+        """Whether a method of this class is public.
+
+        Parameters:
+            name: The method name.
+
+        Returns:
+            Whether the class has a method of this name that is public.
+        """
+        return _is_public_method(self.members.get(name))
+
+
+class ClassFolder(InheritanceMixin, Folder):
     kind: Kind = Kind.CLASS
 
     def __init__(self, *args, classfile: Class | None = None, **kwargs: Any) -> None:
@@ -874,11 +1216,11 @@ class ClassFolder(Folder):
         return self.classfile.constructor
 
     @property
-    def resolved_bases(self) -> list[Object]:
-        """The resolved bases of this class."""
+    def bases(self) -> list[str]:
+        """The bases declared in the class file of this class folder."""
         if self.classfile is None:
             return []
-        return self.classfile.resolved_bases
+        return self.classfile.bases
 
     @property
     def inherited_members(self) -> dict[str, Alias]:
@@ -887,6 +1229,45 @@ class ClassFolder(Folder):
             return {}
         return self.classfile.inherited_members
 
+    @property
+    def enumeration_values(self) -> list[Enumeration]:
+        """The members declared in the `enumeration` blocks of the class file."""
+        if self.classfile is None:
+            return []
+        return self.classfile.enumeration_values
+
+    @property
+    def is_enumeration(self) -> bool:
+        """Whether this class is an enumeration class."""
+        return bool(self.enumeration_values)
+
+    def getter_for(self, name: str) -> Function | None:
+        """The `get.<name>` method of a property of this class, if any.
+
+        See also: [`getter_for`][maxx.objects.Class.getter_for].
+        """
+        if self.classfile is None:
+            return None
+        return self.classfile.getter_for(name)
+
+    def setter_for(self, name: str) -> Function | None:
+        """The `set.<name>` method of a property of this class, if any.
+
+        See also: [`setter_for`][maxx.objects.Class.setter_for].
+        """
+        if self.classfile is None:
+            return None
+        return self.classfile.setter_for(name)
+
+    def is_method_public(self, name: str) -> bool:
+        """Whether a method of this class is public, declared in the class file or in its own file.
+
+        See also: [`is_method_public`][maxx.objects.Class.is_method_public].
+        """
+        if self.classfile is not None and name in self.classfile.members:
+            return self.classfile.is_method_public(name)
+        return _is_public_method(self.members.get(name))
+
 
 class Enumeration(PathMixin, Object):
     """This class represents a MATLAB enumeration."""
@@ -918,6 +1299,19 @@ class Enumeration(PathMixin, Object):
         return self.name == other.name and self.parent == other.parent
 
 
+INHERIT_DOCSTRING = re.compile(r"^\s*see parent\.?\s*$", re.IGNORECASE | re.MULTILINE)
+"""A docstring line marking that the documentation of the overridden method applies."""
+
+
+def _is_documented(docstring: Docstring | None) -> bool:
+    """Whether a docstring documents its object, i.e. it is not empty nor a `See parent` marker."""
+    return (
+        docstring is not None
+        and bool(docstring.value.strip())
+        and INHERIT_DOCSTRING.search(docstring.value) is None
+    )
+
+
 class Function(PathMixin, Object):
     """The class representing a MATLAB function."""
 
@@ -935,6 +1329,20 @@ class Function(PathMixin, Object):
         Static: bool = False,
         setter: bool = False,
         getter: bool = False,
+        return_sites: list[int] | None = None,
+        trailing_return: bool = False,
+        error_ids: list[str] | None = None,
+        warning_ids: list[str] | None = None,
+        try_catch_count: int = 0,
+        has_empty_catch: bool = False,
+        calls_error: bool = False,
+        assigns_input_fields: bool = False,
+        has_arguments_block: bool = False,
+        nargin_sites: list[int] | None = None,
+        variables: list[str] | None = None,
+        local_functions: list[Function] | None = None,
+        test_role: TestRole | None = None,
+        is_function_test: bool = False,
         **kwargs: Any,
     ) -> None:
         """Initialize the function.
@@ -944,6 +1352,20 @@ class Function(PathMixin, Object):
             arguments: The function arguments.
             returns: The function return type.
             decorators: The function decorators, if any.
+            return_sites: The line numbers of the `return` statements.
+            trailing_return: Whether the function body ends with a `return` statement.
+            error_ids: The identifiers of the errors raised with `error`.
+            warning_ids: The identifiers of the warnings issued with `warning`.
+            try_catch_count: The number of `try` statements.
+            has_empty_catch: Whether a `catch` clause has no statements.
+            calls_error: Whether the function body calls `error`.
+            assigns_input_fields: Whether the function body assigns to a field of an input.
+            has_arguments_block: Whether the function has an `arguments` block.
+            nargin_sites: The line numbers at which `nargin` or `nargout` is used.
+            variables: The names assigned or iterated over in the function body.
+            local_functions: The functions defined after the main function in its file.
+            test_role: The role of the method in a test class, if any.
+            is_function_test: Whether the function is the main function of a function-based test.
             **kwargs: See [`griffe.Object`][].
         """
         super().__init__(*args, **kwargs)
@@ -958,6 +1380,34 @@ class Function(PathMixin, Object):
         self.Hidden: bool = Hidden
         self.is_setter: bool = setter
         self.is_getter: bool = getter
+        self.return_sites: list[int] = return_sites or []
+        """The line numbers of the `return` statements in the function body."""
+        self.trailing_return: bool = trailing_return
+        """Whether the function body ends with a `return` statement."""
+        self.error_ids: list[str] = error_ids or []
+        """The identifiers of the errors the function raises, e.g. `myapp:badInput`."""
+        self.warning_ids: list[str] = warning_ids or []
+        """The identifiers of the warnings the function issues."""
+        self.try_catch_count: int = try_catch_count
+        """The number of `try` statements in the function body."""
+        self.has_empty_catch: bool = has_empty_catch
+        """Whether a `catch` clause in the function body silently discards the error."""
+        self.calls_error: bool = calls_error
+        """Whether the function body calls `error`, with or without an identifier."""
+        self.assigns_input_fields: bool = assigns_input_fields
+        """Whether the body assigns to a field of an input that is not returned, e.g. `obj.X`."""
+        self.has_arguments_block: bool = has_arguments_block
+        """Whether the function validates its arguments in an `arguments` block."""
+        self.nargin_sites: list[int] = nargin_sites or []
+        """The line numbers at which the function body uses `nargin` or `nargout`."""
+        self.variables: list[str] = variables or []
+        """The names assigned or iterated over in the function body, excluding nested functions."""
+        self.local_functions: list[Function] = local_functions or []
+        """The local functions of the file, if this is the main function of a function file."""
+        self.test_role: TestRole | None = test_role
+        """The role of the method in a test class, from the attributes of its `methods` block."""
+        self.is_function_test: bool = is_function_test
+        """Whether the function returns `functiontests(localfunctions)`, on a best-effort basis."""
 
         for argument in self.arguments:
             argument.function = self
@@ -981,11 +1431,156 @@ class Function(PathMixin, Object):
         """Whether this function is a method."""
         return self.parent is not None and self.parent.is_class
 
+    @property
+    def has_early_return(self) -> bool:
+        """Whether the function returns before reaching the end of its body."""
+        if len(self.return_sites) > 1:
+            return True
+        return bool(self.return_sites) and not self.trailing_return
+
+    @property
+    def modifies_inputs(self) -> bool:
+        """Whether the function may modify its inputs in place, on a best-effort basis.
+
+        Only handle objects are passed by reference, so this holds for property set methods and
+        for functions assigning to a property of an input. Whether the input is a handle object
+        is not checked.
+        """
+        return (self.is_method and self.is_setter) or self.assigns_input_fields
+
+    @property
+    def may_throw(self) -> bool:
+        """Whether the function calls `error` without any `try` statement to handle errors."""
+        return self.calls_error and self.try_catch_count == 0
+
+    @property
+    def mixes_nargin_with_arguments(self) -> bool:
+        """Whether the function uses `nargin` or `nargout` besides an `arguments` block."""
+        return self.has_arguments_block and bool(self.nargin_sites)
+
+    @property
+    def name_value_args(self) -> list[Argument]:
+        """The name-value arguments of the function, in order of declaration."""
+        return [argument for argument in self.arguments if argument.is_name_value]
+
+    @property
+    def has_repeating_args(self) -> bool:
+        """Whether any of the function arguments is declared in an `arguments (Repeating)` block."""
+        return any(argument.repeating for argument in self.arguments)
+
+    def validate_argument_order(self) -> None:
+        """Check that the arguments of the function are declared in a valid order.
+
+        Positional arguments come first, followed by optional arguments, and finally by either
+        name-value arguments or `varargin`. Arguments without a kind are not checked.
+
+        Raises:
+            ArgumentOrderError: If an argument comes after an argument of a later kind.
+        """
+        order = [
+            (ArgumentKind.positional_only,),
+            (ArgumentKind.optional,),
+            (ArgumentKind.keyword_only, ArgumentKind.varargin),
+        ]
+        rank = 0
+        for argument in self.arguments:
+            if argument.kind is None:
+                continue
+            current = next(index for index, kinds in enumerate(order) if argument.kind in kinds)
+            if current < rank:
+                expected = " or ".join(kind.value for kinds in order[rank:] for kind in kinds)
+                raise ArgumentOrderError(argument.name, argument.kind.value, expected)
+            rank = current
+
     @property
     def is_constructor_method(self) -> bool:
         """Whether this function is a constructor method."""
         return self.is_method and self.parent is not None and self.name == self.parent.name
 
+    def is_test(
+        self,
+        cls: "Class | ClassFolder | None" = None,
+        paths_collection: "PathsCollection | None" = None,
+    ) -> bool:
+        """Whether this function is a unit test.
+
+        Methods in a `methods (Test)` block and the main function of a function-based test are
+        tests. Other functions are tests when their name starts with `test`, case-insensitively,
+        and the class they belong to, if any, derives from `matlab.unittest.TestCase`.
+
+        Parameters:
+            cls: The class the function belongs to. Defaults to the parent of a method.
+            paths_collection: The collection used to resolve the bases of the class.
+                Defaults to the collection attached to the class.
+
+        Returns:
+            Whether the function is a test.
+        """
+        if self.test_role is TestRole.test or self.is_function_test:
+            return True
+        if not self.name.casefold().startswith("test"):
+            return False
+        if cls is None and isinstance(self.parent, (Class, ClassFolder)):
+            cls = self.parent
+        return cls is None or cls.is_test_class(paths_collection)
+
+    def effective_docstring(
+        self, paths_collection: "PathsCollection | None" = None
+    ) -> Docstring | None:
+        """The docstring of this function, or the one of the method it overrides.
+
+        Like MATLAB's `help`, a method without documentation falls back to the nearest base class
+        that documents a method of the same name. A `See parent` line in the docstring forces this
+        fallback, even if the method has a stub comment.
+
+        Parameters:
+            paths_collection: The collection used to resolve the bases.
+                Defaults to the collection attached to the class.
+
+        Returns:
+            The effective docstring, or None if neither the method nor its bases document it.
+        """
+        return self._effective_docstring(paths_collection)[0]
+
+    def docstring_inherited_from(
+        self, paths_collection: "PathsCollection | None" = None
+    ) -> str | None:
+        """The path of the base class the effective docstring is inherited from.
+
+        See also: [`effective_docstring`][maxx.objects.Function.effective_docstring].
+
+        Parameters:
+            paths_collection: The collection used to resolve the bases.
+                Defaults to the collection attached to the class.
+
+        Returns:
+            The path of the base class, or None if the docstring is not inherited.
+        """
+        origin = self._effective_docstring(paths_collection)[1]
+        return origin.path if origin is not None else None
+
+    def _effective_docstring(
+        self, paths_collection: "PathsCollection | None"
+    ) -> tuple[Docstring | None, Class | ClassFolder | None]:
+        parent = self.parent
+        if (
+            _is_documented(self.docstring)
+            or not isinstance(parent, (Class, ClassFolder))
+            or self.name == parent.name
+        ):
+            return self.docstring, None
+
+        for model in parent._lineage(paths_collection)[1:]:
+            member = model.members.get(self.name)
+            if isinstance(member, Alias):
+                try:
+                    member = member.target
+                except (CyclicAliasError, ValueError):
+                    continue
+            if isinstance(member, Function) and _is_documented(member.docstring):
+                return member.docstring, model
+        return self.docstring, None
+
     @property
     def attributes(self) -> set[str]:
         attributes = set()
@@ -0,0 +1,124 @@
+"""Resolve the bare identifiers used in a function, following MATLAB's precedence rules."""
+
+from __future__ import annotations
+
+from typing import TYPE_CHECKING
+
+from maxx.enums import ReferenceKind
+from maxx.exceptions import FilePathError
+from maxx.expressions import Reference, resolve_name
+from maxx.objects import Class, ClassFolder, Function, Object
+
+if TYPE_CHECKING:
+    from maxx.collection import PathsCollection
+
+__all__ = ["NameResolver"]
+
+
+def _variables(function: Function) -> set[str]:
+    """
+    Find the variables of a function.
+
+    Args:
+        function: The function.
+
+    Returns:
+        The names of its arguments and outputs, and the names that are assigned or iterated over
+        in its body.
+    """
+    return {
+        *(argument.name for argument in (*function.arguments, *function.returns)),
+        *function.variables,
+    }
+
+
+def _local_functions(function: Function) -> list[Function]:
+    """
+    Find the local functions that can be called from a function.
+
+    Args:
+        function: The function.
+
+    Returns:
+        The local functions of the file of a main function, or those defined after the
+        `classdef` block of the class of a method.
+    """
+    if function.local_functions:
+        return function.local_functions
+    parent = function.parent
+    if isinstance(parent, ClassFolder):
+        parent = parent.classfile
+    if isinstance(parent, Class):
+        return parent.local_functions
+    return []
+
+
+class NameResolver:
+    """
+    Resolve the identifiers used in functions, e.g. to find all references to a function.
+
+    Identifiers are looked up as MATLAB does: variables first, then local functions of the same
+    file, functions in the `private` folder next to the file, objects on the search path of the
+    collection and finally MATLAB builtins.
+    """
+
+    def __init__(self, paths_collection: PathsCollection) -> None:
+        """
+        Initialize the resolver.
+
+        Args:
+            paths_collection: The collection used to look up private functions and objects.
+        """
+        self.paths_collection: PathsCollection = paths_collection
+        """The collection used to look up private functions and objects."""
+
+    def resolve(self, name: str, context: Function) -> list[Reference]:
+        """
+        Resolve an identifier used in a function.
+
+        Args:
+            name: The identifier, e.g. `foo` in a call `foo()`.
+            context: The function in which the identifier is used.
+
+        Returns:
+            Every candidate the identifier can refer to, the one MATLAB picks first followed by
+            the ones it shadows, or a single unknown reference if none is found.
+        """
+        candidates: list[Reference] = []
+        if name in _variables(context):
+            candidates.append(Reference(name, ReferenceKind.variable))
+
+        for function in _local_functions(context):
+            if function.name == name and function is not context:
+                candidates.append(
+                    Reference(name, ReferenceKind.local_function, path=function.canonical_path)
+                )
+
+        private = self._private_function(name, context)
+        if private is not None:
+            candidates.append(
+                Reference(name, ReferenceKind.private_function, path=private.canonical_path)
+            )
+
+        candidates.extend(resolve_name(name, self.paths_collection))
+        return candidates or [Reference(name, ReferenceKind.unknown)]
+
+    def _private_function(self, name: str, context: Function) -> Object | None:
+        """
+        Find a function in the `private` folder next to the file of a function.
+
+        Args:
+            name: The name of the function.
+            context: The function from which it is called.
+
+        Returns:
+            The private function, or None if there is none.
+        """
+        try:
+            directory = context.filepath.parent
+        except FilePathError:
+            return None
+        if directory.name == "private":
+            # Private functions can call each other.
+            directory = directory.parent
+        return self.paths_collection.private_member(directory, name)
@@ -0,0 +1,86 @@
+"""Statistics on the time spent collecting and parsing MATLAB files."""
+
+from __future__ import annotations
+
+from dataclasses import dataclass, field
+from pathlib import Path
+
+from maxx.enums import Kind
+
+__all__ = ["CollectionStats", "FileStats"]
+
+
+@dataclass(frozen=True)
+class FileStats:
+    """The time spent parsing a single file."""
+
+    path: Path
+    """The path of the file."""
+    size: int
+    """The size of the file in bytes."""
+    parse_time: float
+    """The time spent reading and parsing the file, in seconds."""
+
+
+@dataclass
+class CollectionStats:
+    """
+    The time spent collecting and parsing the files of a paths collection, and what they contain.
+
+    Files are parsed when their objects are first resolved, so the statistics grow as the
+    collection is used. The objects are only counted by
+    [`statistics`][maxx.collection.PathsCollection.statistics], which resolves all files.
+
+    Timing events are also logged at the `TRACE` level, and only show up when the log level is
+    configured accordingly, e.g. with `maxx.logger.configure(level="TRACE")`.
+    """
+
+    walk_time: float = 0.0
+    """The time spent walking the directories added to the collection, in seconds."""
+    files: list[FileStats] = field(default_factory=list)
+    """The statistics of each parsed file, in the order they were parsed."""
+    files_scanned: int = 0
+    """The number of collected files, whether they were parsed or not."""
+    files_skipped: int = 0
+    """The number of files left out of the collection because of their size."""
+    parse_errors: list[tuple[Path, str]] = field(default_factory=list)
+    """The file and message of each problem encountered while reading or parsing a file."""
+    by_kind: dict[Kind, int] = field(default_factory=dict)
+    """The number of objects of each kind, including the members of classes and namespaces."""
+
+    @property
+    def total_objects(self) -> int:
+        """The number of objects, including the members of classes and namespaces."""
+        return sum(self.by_kind.values())
+
+    @property
+    def files_parsed(self) -> int:
+        """The number of parsed files."""
+        return len(self.files)
+
+    @property
+    def bytes_parsed(self) -> int:
+        """The total size of the parsed files in bytes."""
+        return sum(file.size for file in self.files)
+
+    @property
+    def parse_time(self) -> float:
+        """The total time spent parsing files, in seconds."""
+        return sum(file.parse_time for file in self.files)
+
+    @property
+    def total_time(self) -> float:
+        """The total time spent walking directories and parsing files, in seconds."""
+        return self.walk_time + self.parse_time
+
+    def slowest(self, n: int = 10) -> list[FileStats]:
+        """
+        Get the files that took the longest to parse.
+
+        Args:
+            n: The maximum number of files to return.
+
+        Returns:
+            The slowest files, slowest first.
+        """
+        return sorted(self.files, key=lambda file: file.parse_time, reverse=True)[:n]
@@ -2,11 +2,16 @@
 
 from __future__ import annotations
 
+import re
+import sys
 import textwrap
+import threading
 import warnings
 from collections import OrderedDict
+from contextlib import contextmanager
+from dataclasses import dataclass, field
 from pathlib import Path
-from typing import TYPE_CHECKING, Any
+from typing import TYPE_CHECKING, Any, Iterator
 
 import charset_normalizer
 import tree_sitter_matlab as tsmatlab
@@ -14,8 +19,8 @@ from loguru import logger
 from tree_sitter import Language, Node, Parser, Query, QueryCursor, Tree, TreeCursor
 
 from maxx.config import ParserConfig
-from maxx.enums import AccessKind, ArgumentKind
-from maxx.expressions import Expr
+from maxx.enums import AccessKind, ArgumentKind, ParseIssueKind, TestRole
+from maxx.expressions import Expr, parse_validators
 from maxx.objects import (
     Argument,
     Arguments,
@@ -31,7 +36,14 @@ if TYPE_CHECKING:
     from maxx.collection import PathsCollection
 
 
-__all__ = ["FileParser"]
+__all__ = [
+    "Comment",
+    "FileParser",
+    "ParseIssue",
+    "ParseResult",
+    "ParserPool",
+    "StringLiteral",
+]
 
 
 with warnings.catch_warnings():
@@ -39,10 +51,51 @@ with warnings.catch_warnings():
     LANGUAGE = Language(tsmatlab.language())
 PARSER = Parser(LANGUAGE)
 
-FILE_QUERY = QueryCursor(
-    Query(
-        LANGUAGE,
-        """
+_QUERY_SOURCES: dict[str, str] = {}
+_QUERIES: dict[str, QueryCursor] = {}
+
+
+def _query(name: str, source: str) -> QueryCursor:
+    """
+    Compile a query for the MATLAB language, keeping its source to compile it for other languages.
+
+    Args:
+        name: The name of the query.
+        source: The query source.
+
+    Returns:
+        The query cursor.
+    """
+    _QUERY_SOURCES[name] = source
+    _QUERIES[name] = QueryCursor(Query(LANGUAGE, source))
+    return _QUERIES[name]
+
+
+def _compile_queries(language: Language) -> dict[str, QueryCursor]:
+    """
+    Compile the queries for another tree-sitter grammar of MATLAB.
+
+    Args:
+        language: The tree-sitter language of the grammar.
+
+    Returns:
+        The query cursors, by name.
+
+    Raises:
+        ValueError: If a query cannot be compiled, e.g. because the grammar has other node types.
+    """
+    queries: dict[str, QueryCursor] = {}
+    for name, source in _QUERY_SOURCES.items():
+        try:
+            queries[name] = QueryCursor(Query(language, source))
+        except Exception as ex:
+            raise ValueError(f"{name} cannot be compiled for the language: {ex}") from ex
+    return queries
+
+
+FILE_QUERY = _query(
+    "FILE_QUERY",
+    """
 (source_file .
     (comment)* @header .
     [
@@ -51,14 +104,12 @@ FILE_QUERY = QueryCursor(
     ]?
 )
 """,
-    )
 )
 
 
-FUNCTION_QUERY = QueryCursor(
-    Query(
-        LANGUAGE,
-        """
+FUNCTION_QUERY = _query(
+    "FUNCTION_QUERY",
+    """
 (function_definition .
     ("function")
     (function_output .
@@ -86,14 +137,12 @@ FUNCTION_QUERY = QueryCursor(
     (comment)* @docstring
     (arguments_statement)* @arguments
 )""",
-    )
 )
 
 
-ARGUMENTS_QUERY = QueryCursor(
-    Query(
-        LANGUAGE,
-        """
+ARGUMENTS_QUERY = _query(
+    "ARGUMENTS_QUERY",
+    """
 (arguments_statement .
     ("arguments")
     (attributes
@@ -107,14 +156,12 @@ ARGUMENTS_QUERY = QueryCursor(
         ("\\n")
     ]+
 )""",
-    )
 )
 
 
-PROPERTY_QUERY = QueryCursor(
-    Query(
-        LANGUAGE,
-        """
+PROPERTY_QUERY = _query(
+    "PROPERTY_QUERY",
+    """
 (property .
     [
         (identifier) @name
@@ -141,14 +188,12 @@ PROPERTY_QUERY = QueryCursor(
         _+ @default
     )?
 )""",
-    )
 )
 
 
-ATTRIBUTE_QUERY = QueryCursor(
-    Query(
-        LANGUAGE,
-        """
+ATTRIBUTE_QUERY = _query(
+    "ATTRIBUTE_QUERY",
+    """
 (attribute
     (identifier) @name
     (
@@ -156,14 +201,12 @@ ATTRIBUTE_QUERY = QueryCursor(
         _+ @value
     )?
 )""",
-    )
 )
 
 
-CLASS_QUERY = QueryCursor(
-    Query(
-        LANGUAGE,
-        """
+CLASS_QUERY = _query(
+    "CLASS_QUERY",
+    """
 ("classdef" .
     (attributes
         (attribute) @attributes
@@ -182,14 +225,12 @@ CLASS_QUERY = QueryCursor(
         ("\\n")
     ]*
 )""",
-    )
 )
 
 
-METHODS_QUERY = QueryCursor(
-    Query(
-        LANGUAGE,
-        """
+METHODS_QUERY = _query(
+    "METHODS_QUERY",
+    """
 (methods .
     [
         (comment)
@@ -208,13 +249,11 @@ METHODS_QUERY = QueryCursor(
         ]*
     )*
 )""",
-    )
 )
 
-PROPERTIES_QUERY = QueryCursor(
-    Query(
-        LANGUAGE,
-        """
+PROPERTIES_QUERY = _query(
+    "PROPERTIES_QUERY",
+    """
 ("properties" .
     (comment)* .
     (attributes
@@ -230,13 +269,11 @@ PROPERTIES_QUERY = QueryCursor(
         ]*
     )*
 )""",
-    )
 )
 
-ENUMERATIONS_QUERY = QueryCursor(
-    Query(
-        LANGUAGE,
-        """
+ENUMERATIONS_QUERY = _query(
+    "ENUMERATIONS_QUERY",
+    """
 ("enumeration" .
     ("\\n")* .
     [
@@ -252,7 +289,6 @@ ENUMERATIONS_QUERY = QueryCursor(
         ("\\n")
     ]*
 )""",
-    )
 )
 
 
@@ -287,6 +323,261 @@ def _dedent(lines: list[str]) -> list[str]:
     return dedented_text.split("\n")
 
 
+def _dimensions(sizes: list[str]) -> list[int | None]:
+    """
+    Convert the sizes of a size validation to typed dimensions.
+
+    Args:
+        sizes: The decoded sizes, e.g. `["1", ":"]` for `(1,:)`.
+
+    Returns:
+        The size of each dimension, with `None` for dimensions of any size (`:`).
+    """
+    return [None if size == ":" else int(size) for size in sizes]
+
+
+def _body_nodes(node: Node) -> Iterator[Node]:
+    """
+    Iterate over the nodes in the body of a function, leaving out nested functions.
+
+    Args:
+        node: The function definition node.
+
+    Yields:
+        Node: The nodes below the function definition in order of appearance, except for nested
+            function definitions and their nodes.
+    """
+    stack: list[Node] = list(reversed(node.children))
+    while stack:
+        child = stack.pop()
+        if child.type == "function_definition":
+            continue
+        yield child
+        stack.extend(reversed(child.children))
+
+
+def _return_sites(node: Node) -> tuple[list[int], bool]:
+    """
+    Find the `return` statements in the body of a function.
+
+    Args:
+        node: The function definition node.
+
+    Returns:
+        The line numbers of the `return` statements, excluding those of nested functions,
+        and whether the body ends with a `return` statement.
+    """
+    sites = [
+        child.start_point.row + 1 for child in _body_nodes(node) if child.type == "return_statement"
+    ]
+
+    trailing = False
+    body = next((child for child in node.children if child.type == "block"), None)
+    if body is not None:
+        statements = [child for child in body.named_children if child.type != "comment"]
+        trailing = bool(statements) and statements[-1].type == "return_statement"
+    return sites, trailing
+
+
+def _nargin_sites(node: Node) -> list[int]:
+    """
+    Find the uses of `nargin` and `nargout` in the body of a function.
+
+    Args:
+        node: The function definition node.
+
+    Returns:
+        The line numbers of the `nargin` and `nargout` identifiers, excluding those of nested
+        functions.
+    """
+    return [
+        child.start_point.row + 1
+        for child in _body_nodes(node)
+        if child.type == "identifier" and child.text in (b"nargin", b"nargout")
+    ]
+
+
+MESSAGE_ID = re.compile(r"[A-Za-z][\w-]*(?::[\w-]+)+")
+"""The format of the identifiers of errors and warnings, e.g. `MATLAB:badarg` or `myapp:io:read`."""
+
+
+def _diagnostic_ids(node: Node, encoding: str) -> tuple[list[str], list[str]]:
+    """
+    Find the identifiers of the errors and warnings raised in the body of a function.
+
+    Only calls such as `error('myapp:kind', 'message')` are recognized, where the identifier is a
+    literal. With a single argument, `error` and `warning` take a message instead of an identifier.
+
+    Args:
+        node: The function definition node.
+        encoding: The encoding of the file.
+
+    Returns:
+        The unique error identifiers and warning identifiers in order of appearance, excluding
+        those of nested functions.
+    """
+    ids: dict[str, dict[str, None]] = {"error": {}, "warning": {}}
+    for child in _body_nodes(node):
+        if child.type != "function_call":
+            continue
+
+        name = child.child_by_field_name("name")
+        if name is None or name.type != "identifier" or name.text not in (b"error", b"warning"):
+            continue
+        arguments = next((item for item in child.named_children if item.type == "arguments"), None)
+        if arguments is None:
+            continue
+        values = [item for item in arguments.named_children if item.type != "comment"]
+        if len(values) < 2 or values[0].type != "string" or values[0].text is None:
+            continue
+        identifier = values[0].text.decode(encoding)[1:-1]
+        if MESSAGE_ID.fullmatch(identifier):
+            ids[name.text.decode(encoding)][sys.intern(identifier)] = None
+    return list(ids["error"]), list(ids["warning"])
+
+
+def _exception_handling(node: Node) -> tuple[int, bool, bool]:
+    """
+    Find the `try` statements and `error` calls in the body of a function.
+
+    Args:
+        node: The function definition node.
+
+    Returns:
+        The number of `try` statements, whether any of their `catch` clauses has no statements,
+        and whether `error` is called, excluding nested functions.
+    """
+    try_count = 0
+    empty_catch = False
+    calls_error = False
+    for child in _body_nodes(node):
+        if child.type == "try_statement":
+            try_count += 1
+        elif child.type == "catch_clause":
+            body = next((item for item in child.children if item.type == "block"), None)
+            if body is None or all(item.type == "comment" for item in body.named_children):
+                empty_catch = True
+        elif child.type == "function_call":
+            name = child.child_by_field_name("name")
+            if name is not None and name.type == "identifier" and name.text == b"error":
+                calls_error = True
+    return try_count, empty_catch, calls_error
+
+
+def _assigns_input_fields(
+    node: Node, inputs: list[str] | None, outputs: list[str] | None, encoding: str
+) -> bool:
+    """
+    Whether the body of a function assigns to a field or property of one of its inputs.
+
+    Inputs that are also outputs, as in `function obj = update(obj)`, are left out, as their
+    changes are returned rather than made in place.
+
+    Args:
+        node: The function definition node.
+        inputs: The names of the input arguments.
+        outputs: The names of the output arguments.
+        encoding: The encoding of the file.
+
+    Returns:
+        Whether any assignment, excluding those of nested functions, targets a field of an input,
+        such as `obj.Value = 1`, `obj.Value(2) = 1` or `s.(name) = 1`.
+    """
+    modified = set(inputs or ()) - set(outputs or ())
+    if not modified:
+        return False
+    for child in _body_nodes(node):
+        if child.type != "assignment":
+            continue
+        left = child.child_by_field_name("left")
+        if left is None:
+            continue
+        targets = left.named_children if left.type == "multioutput_variable" else [left]
+        for target in targets:
+            if target.type != "field_expression":
+                continue
+            object = target.child_by_field_name("object")
+            if (
+                object is not None
+                and object.type == "identifier"
+                and object.text is not None
+                and object.text.decode(encoding) in modified
+            ):
+                return True
+    return False
+
+
+def _variables(node: Node, encoding: str) -> list[str]:
+    """
+    Find the names a function assigns or iterates over, which MATLAB treats as variables.
+
+    Args:
+        node: The function definition node.
+        encoding: The encoding of the file.
+
+    Returns:
+        The sorted names of the variables, excluding those of nested functions.
+    """
+    names: set[str] = set()
+    for child in _body_nodes(node):
+        if child.type == "assignment":
+            left = child.child_by_field_name("left")
+            if left is None:
+                continue
+            targets = [left] if left.type == "identifier" else left.named_children
+        elif child.type == "iterator":
+            targets = child.named_children[:1]
+        else:
+            continue
+        for target in targets:
+            if target.type == "identifier" and target.text:
+                names.add(target.text.decode(encoding))
+    return sorted(names)
+
+
+def _is_function_test(node: Node) -> bool:
+    """
+    Whether a function is the main function of a function-based test file.
+
+    Such functions return the tests created from their local functions, e.g.
+    `tests = functiontests(localfunctions)`. Any call to `functiontests` in the body is accepted.
+
+    Args:
+        node: The function definition node.
+
+    Returns:
+        Whether the function body calls `functiontests`, excluding nested functions.
+    """
+    for child in _body_nodes(node):
+        if child.type == "function_call":
+            name = child.child_by_field_name("name")
+            if name is not None and name.type == "identifier" and name.text == b"functiontests":
+                return True
+    return False
+
+
+def _descendants(node: Node, node_type: str) -> list[Node]:
+    """
+    Find the nodes of a type in a syntax tree, without looking inside the found nodes.
+
+    Args:
+        node: The root of the tree to search.
+        node_type: The type of the nodes to find.
+
+    Returns:
+        The found nodes, sorted by their start point.
+    """
+    nodes: list[Node] = []
+    stack: list[Node] = [node]
+    while stack:
+        child = stack.pop()
+        if child.type == node_type:
+            nodes.append(child)
+        else:
+            stack.extend(child.children)
+    return _sort_nodes(nodes)
+
+
 def _sort_nodes(nodes: list[Node]) -> list[Node]:
     """
     Sort a list of nodes based on their start point.
@@ -300,6 +591,177 @@ def _sort_nodes(nodes: list[Node]) -> list[Node]:
     return sorted(nodes, key=lambda node: node.start_point)
 
 
+@dataclass
+class ParseIssue:
+    """A problem encountered while reading or parsing a file."""
+
+    kind: ParseIssueKind
+    """Whether the file could not be read, decoded or parsed."""
+    message: str
+    """A description of the problem."""
+    lineno: int | None = None
+    """The line of the problem, if it is located in the file."""
+
+    def __str__(self) -> str:
+        location = f" (line {self.lineno})" if self.lineno is not None else ""
+        return f"{self.kind.value} error{location}: {self.message}"
+
+
+@dataclass
+class ParseResult:
+    """The result of tolerantly parsing a file."""
+
+    object: Function | Class | Script | None
+    """The parsed object, possibly incomplete, or None if nothing could be recovered."""
+    errors: list[ParseIssue] = field(default_factory=list)
+    """The problems encountered while parsing the file."""
+
+
+@dataclass(frozen=True)
+class Comment:
+    """A comment in a file."""
+
+    text: str
+    """The text of the comment, including the comment characters."""
+    line: int
+    """The line on which the comment starts."""
+    column: int
+    """The column at which the comment starts, counting from zero."""
+    is_doc_comment: bool = False
+    """Whether the comment documents a function, class or property, as their docstring."""
+
+
+@dataclass(frozen=True)
+class StringLiteral:
+    """A string or character array literal in a file."""
+
+    value: str
+    """The value of the literal, without quotes and with doubled quotes unescaped."""
+    line: int
+    """The line on which the literal starts."""
+    column: int
+    """The column at which the literal starts, counting from zero."""
+    is_char_array: bool = False
+    """Whether the literal is a character array in single quotes, rather than a string."""
+
+
+DOCUMENTED_NODES = ("function_definition", "class_definition", "property")
+"""The nodes that are documented by the comments directly preceding them."""
+DECLARATION_NODES = ("identifier", "function_arguments", "superclasses")
+"""The last nodes of function and class declarations, which their docstring directly follows."""
+
+
+def _is_doc_comment(node: Node, doc_comments: set[int]) -> bool:
+    """
+    Whether a comment documents a function, class or property.
+
+    Args:
+        node: The comment node.
+        doc_comments: The start bytes of the preceding comments that are doc comments.
+
+    Returns:
+        Whether the comment directly precedes a function, class or property, directly follows
+        the declaration of a function or class or a property on the same line, or continues a
+        doc comment.
+    """
+    # Look past the comments that directly follow, for docstrings of several comment nodes.
+    end = node
+    following = node.next_named_sibling
+    while following is not None and following.start_point.row <= end.end_point.row + 1:
+        if following.type in DOCUMENTED_NODES:
+            return True
+        if following.type != "comment":
+            break
+        end = following
+        following = following.next_named_sibling
+
+    previous = node.prev_named_sibling
+    if previous is None:
+        return False
+    if previous.type == "comment":
+        return (
+            previous.start_byte in doc_comments
+            and previous.end_point.row + 1 >= node.start_point.row
+        )
+    if previous.type == "property":
+        return previous.end_point.row == node.start_point.row
+    parent = node.parent
+    return (
+        parent is not None
+        and parent.type in ("function_definition", "class_definition")
+        and previous.type in DECLARATION_NODES
+    )
+
+
+def _syntax_issues(node: Node) -> list[ParseIssue]:
+    """
+    Find the syntax errors that tree-sitter recovered from.
+
+    Args:
+        node: The root node of the parsed file.
+
+    Returns:
+        An issue for each erroneous or missing node, in order of appearance.
+    """
+    issues: list[ParseIssue] = []
+    stack: list[Node] = [node]
+    while stack:
+        child = stack.pop()
+        if child.is_missing:
+            issues.append(
+                ParseIssue(
+                    ParseIssueKind.syntax, f"Missing {child.type}", child.start_point.row + 1
+                )
+            )
+        elif child.is_error:
+            issues.append(
+                ParseIssue(ParseIssueKind.syntax, "Syntax error", child.start_point.row + 1)
+            )
+        elif child.has_error:
+            stack.extend(reversed(child.children))
+    return issues
+
+
+class ParserPool:
+    """
+    A pool of tree-sitter parsers that are reused across files.
+
+    A parser is created only when all existing parsers are in use, so that files in a collection
+    can be parsed from multiple threads, which a single shared parser does not allow.
+
+    Methods:
+        parser() -> Iterator[Parser]: Context manager that borrows a parser from the pool.
+    """
+
+    def __init__(self) -> None:
+        self._idle: list[Parser] = []
+        self._lock = threading.Lock()
+
+    def __reduce__(self) -> tuple[type[ParserPool], tuple[()]]:
+        # Parsers cannot be pickled, an unpickled pool starts out empty.
+        return (ParserPool, ())
+
+    def __len__(self) -> int:
+        return len(self._idle)
+
+    @contextmanager
+    def parser(self) -> Iterator[Parser]:
+        """
+        Borrow a parser from the pool, and return it after it is reset.
+
+        Yields:
+            Parser: A tree-sitter parser for MATLAB.
+        """
+        with self._lock:
+            parser = self._idle.pop() if self._idle else Parser(LANGUAGE)
+        try:
+            yield parser
+        finally:
+            parser.reset()
+            with self._lock:
+                self._idle.append(parser)
+
+
 class FileParser(object):
     """
     A class to parse MATLAB files using Tree-sitter.
@@ -311,23 +773,104 @@ class FileParser(object):
 
     Methods:
         parse(**kwargs) -> Object: Parses the MATLAB file and returns a Object.
+        parse_tolerant(**kwargs) -> ParseResult: Parses the MATLAB file, collecting problems
+            instead of raising them.
+        comments() -> list[Comment]: Lists the comments of the MATLAB file with their locations.
+        string_literals() -> list[StringLiteral]: Lists the string literals of the MATLAB file.
+        set_filepath(filepath) -> None: Reads another file to be parsed.
+        set_source(source, filepath) -> None: Sets the content of another file to be parsed.
+        with_parser(parser, filepath) -> FileParser: Creates a parser for a file using an existing
+            tree-sitter parser.
+        with_language(language) -> FileParser: Uses another tree-sitter grammar of MATLAB.
     """
 
-    def __init__(self, filepath: Path, paths_collection: "PathsCollection | None" = None) -> None:
+    def __init__(
+        self,
+        filepath: Path,
+        paths_collection: "PathsCollection | None" = None,
+        source: bytes | None = None,
+    ) -> None:
         """
         Initialize the object with the given file path.
 
         Args:
             filepath (Path): The path to the file to be processed.
+            paths_collection (PathsCollection | None): The collection the file belongs to.
+            source (bytes | None): The raw content to parse instead of reading the file,
+                e.g. for code that is not saved in a file.
         """
-        self.filepath: Path = filepath
         self.paths_collection: PathsCollection | None = paths_collection
-        result = charset_normalizer.from_path(filepath).best()
-        self.encoding: str = result.encoding if result else "utf-8"
+        self._parser: Parser = PARSER
+        self._queries: dict[str, QueryCursor] = _QUERIES
+        if source is None:
+            self.set_filepath(filepath)
+        else:
+            self.set_source(source, filepath)
+        logger.debug(f"Initialized FileParser for {filepath}")
+
+    @classmethod
+    def with_parser(
+        cls, parser: Parser, filepath: Path, paths_collection: "PathsCollection | None" = None
+    ) -> FileParser:
+        """
+        Initialize the object for a file, parsing it with an existing tree-sitter parser.
+
+        Args:
+            parser (Parser): The tree-sitter parser to use, e.g. borrowed from a
+                [`ParserPool`][maxx.treesitter.ParserPool].
+            filepath (Path): The path to the file to be processed.
+            paths_collection (PathsCollection | None): The collection the file belongs to.
+
+        Returns:
+            FileParser: The file parser.
+        """
+        file = cls(filepath, paths_collection=paths_collection)
+        file._parser = parser
+        return file
+
+    def with_language(self, language: Language) -> FileParser:
+        """
+        Parse the file with another tree-sitter grammar of MATLAB, e.g. a pinned or patched one.
+
+        Args:
+            language (Language): The tree-sitter language of the grammar.
+
+        Returns:
+            FileParser: This file parser.
+
+        Raises:
+            ValueError: If the queries used to extract objects cannot be compiled for the grammar.
+        """
+        self._queries = _compile_queries(language)
+        self._parser = Parser(language)
+        return self
+
+    def set_filepath(self, filepath: Path) -> None:
+        """
+        Read a new file to be parsed, so that the parser can be reused across files.
+
+        Args:
+            filepath (Path): The path to the file to be processed.
+        """
         with open(filepath, "rb") as f:
-            self._content: bytes = f.read()
+            source = f.read()
+        self.set_source(source, filepath)
+
+    def set_source(self, source: bytes, filepath: Path) -> None:
+        """
+        Set the content to be parsed, so that the parser can be reused across files.
+
+        Args:
+            source (bytes): The raw content of the MATLAB file.
+            filepath (Path): The path of the file, used to name the parsed object.
+        """
+        self.filepath: Path = filepath
+        result = charset_normalizer.from_bytes(source).best()
+        self.encoding: str = result.encoding if result else "utf-8"
+        self._encoding_detected: bool = result is not None or not source
+        self._content: bytes = source
         self._node: Node | None = None
-        logger.debug(f"Initialized FileParser for {filepath}")
+        self._tree: Tree | None = None
 
     @property
     def content(self):
@@ -359,30 +902,43 @@ class FileParser(object):
             ValueError: If the file could not be parsed.
         """
         try:
-            tree: Tree = PARSER.parse(self._content)
+            tree: Tree = self._parser.parse(self._content)
+            self._tree = tree
             cursor: TreeCursor = tree.walk()
             node: Node | None = cursor.node
 
             if node is None:
                 logger.error(f"Tree-sitter failed to parse file: {self.filepath}")
                 raise ValueError(f"The file {self.filepath} could not be parsed.")
-            captures = FILE_QUERY.captures(node)
+            captures = self._queries["FILE_QUERY"].captures(node)
 
             if config is None:
                 config = ParserConfig()
 
             if TYPE_CHECKING:
                 object: Function | Class | Script | None = None
+            main_node: Node | None = None
             if "function" in captures:
                 logger.debug(f"Parsing function in file: {self.filepath}")
-                object = self._parse_function(captures["function"][0], config, **kwargs)
+                main_node = captures["function"][0]
+                object = self._parse_function(main_node, config, **kwargs)
             elif "type" in captures:
                 logger.debug(f"Parsing class in file: {self.filepath}")
-                object = self._parse_class(captures["type"][0], config, **kwargs)
+                main_node = captures["type"][0]
+                object = self._parse_class(main_node, config, **kwargs)
             else:
                 logger.debug(f"Parsing script in file: {self.filepath}")
                 object = Script(self.filepath.stem, filepath=self.filepath, node=node, **kwargs)
 
+            if main_node is not None and not isinstance(object, Script):
+                # Local functions follow the main function or the class definition in the file.
+                object.local_functions = [
+                    self._parse_function(child, config, name_from_declaration=True, **kwargs)
+                    for child in node.named_children
+                    if child.type == "function_definition"
+                    and child.start_byte > main_node.start_byte
+                ]
+
             if not object.docstring:
                 object.docstring = self._comment_docstring(
                     captures.get("header", None), parent=object
@@ -405,6 +961,88 @@ class FileParser(object):
                 syntax_error.end_offset = self._node.end_point.column + 1
             raise syntax_error from ex
 
+    def parse_tolerant(self, config: ParserConfig | None = None, **kwargs: Any) -> ParseResult:
+        """
+        Parse the content of the file, collecting problems instead of raising them.
+
+        Tree-sitter recovers from syntax errors, so a file with syntax errors still gives an
+        object with whatever could be parsed, together with an issue for each error.
+
+        Args:
+            config: Configuration object controlling parser behavior. If None,
+                default configuration is used.
+            **kwargs: Additional keyword arguments to pass to the parsing methods.
+
+        Returns:
+            ParseResult: The parsed object, if any, and the problems encountered.
+        """
+        errors: list[ParseIssue] = []
+        if not self._encoding_detected:
+            errors.append(
+                ParseIssue(ParseIssueKind.encoding, "Could not detect the encoding, assuming utf-8")
+            )
+        try:
+            self.content
+        except UnicodeDecodeError as ex:
+            errors.append(ParseIssue(ParseIssueKind.encoding, str(ex)))
+
+        try:
+            object = self.parse(config, **kwargs)
+        except SyntaxError as ex:
+            message = f"{ex.msg}: {ex.__cause__}" if ex.__cause__ is not None else ex.msg
+            errors.append(ParseIssue(ParseIssueKind.syntax, message, ex.lineno))
+            return ParseResult(None, errors)
+
+        if self._tree is not None:
+            errors.extend(_syntax_issues(self._tree.root_node))
+        return ParseResult(object, errors)
+
+    def comments(self) -> list[Comment]:
+        """
+        Get all comments in the file, without building the objects they belong to.
+
+        Returns:
+            list[Comment]: The comments, in order of appearance.
+        """
+        tree = self._tree if self._tree is not None else self._parser.parse(self._content)
+        comments: list[Comment] = []
+        doc_comments: set[int] = set()
+        for node in _descendants(tree.root_node, "comment"):
+            is_doc_comment = _is_doc_comment(node, doc_comments)
+            if is_doc_comment:
+                doc_comments.add(node.start_byte)
+            comments.append(
+                Comment(
+                    self._decode(node),
+                    node.start_point.row + 1,
+                    node.start_point.column,
+                    is_doc_comment,
+                )
+            )
+        return comments
+
+    def string_literals(self) -> list[StringLiteral]:
+        """
+        Get all string and character array literals in the file, e.g. for internationalization.
+
+        Returns:
+            list[StringLiteral]: The literals, in order of appearance.
+        """
+        tree = self._tree if self._tree is not None else self._parser.parse(self._content)
+        literals: list[StringLiteral] = []
+        for node in _descendants(tree.root_node, "string"):
+            text = self._decode(node)
+            quote = text[:1]
+            literals.append(
+                StringLiteral(
+                    text[1:-1].replace(quote * 2, quote),
+                    node.start_point.row + 1,
+                    node.start_point.column,
+                    quote == "'",
+                )
+            )
+        return literals
+
     def _parse_class(self, node: Node, config: ParserConfig, **kwargs: Any) -> Class:
         """
         Parse a class node and return a Class or Class object.
@@ -423,7 +1061,7 @@ class FileParser(object):
         """
         self._node = node
         saved_kwargs = {key: value for key, value in kwargs.items()}
-        captures = CLASS_QUERY.captures(node)
+        captures = self._queries["CLASS_QUERY"].captures(node)
 
         bases = self._decode_from_capture(captures, "bases")
         docstring = self._comment_docstring(captures.get("docstring", None))
@@ -454,7 +1092,8 @@ class FileParser(object):
             object.members[enumeration.name] = enumeration
 
         for enumeration_captures in [
-            ENUMERATIONS_QUERY.captures(n) for n in _sort_nodes(captures.get("enumeration", []))
+            self._queries["ENUMERATIONS_QUERY"].captures(n)
+            for n in _sort_nodes(captures.get("enumeration", []))
         ]:
             identifier: str = ""
             value_nodes: list[Node] = []
@@ -485,7 +1124,8 @@ class FileParser(object):
                         add_enum(identifier, after_comments, value_nodes)
 
         for property_captures in [
-            PROPERTIES_QUERY.captures(n) for n in _sort_nodes(captures.get("properties", []))
+            self._queries["PROPERTIES_QUERY"].captures(n)
+            for n in _sort_nodes(captures.get("properties", []))
         ]:
             property_kwargs = {key: value for key, value in saved_kwargs.items()}
             attribute_pairs = [
@@ -496,7 +1136,7 @@ class FileParser(object):
                     "AbortSet",
                     "Abstract",
                     "Constant",
-                    "Dependant",
+                    "Dependent",
                     "GetObservable",
                     "Hidden",
                     "NonCopyable",
@@ -524,17 +1164,19 @@ class FileParser(object):
                         prop.docstring = docstring
                     continue
 
-                property_captures = PROPERTY_QUERY.captures(properties_node)
+                property_captures = self._queries["PROPERTY_QUERY"].captures(properties_node)
 
                 prop = Property(
                     self._first_from_capture(property_captures, "name"),
-                    dimensions=self._decode_from_capture(property_captures, "dimensions")
+                    dimensions=_dimensions(
+                        self._decode_from_capture(property_captures, "dimensions")
+                    )
                     if "dimensions" in property_captures
                     else None,
                     type=Expr(property_captures["type"], self.encoding)
                     if "type" in property_captures
                     else None,
-                    validators=Expr(property_captures["validators"], self.encoding)
+                    validators=parse_validators(self._decode(property_captures["validators"][0]))
                     if "validators" in property_captures
                     else None,
                     default=Expr(property_captures["default"], self.encoding)
@@ -545,12 +1187,15 @@ class FileParser(object):
                     else None,
                     parent=object,
                     node=properties_node,
+                    lineno=properties_node.start_point.row + 1,
+                    endlineno=properties_node.end_point.row + 1,
                     **property_kwargs,
                 )
                 object.members[prop.name] = prop
 
         for method_captures in [
-            METHODS_QUERY.captures(n) for n in _sort_nodes(captures.get("methods", []))
+            self._queries["METHODS_QUERY"].captures(n)
+            for n in _sort_nodes(captures.get("methods", []))
         ]:
             method_kwargs = {key: value for key, value in saved_kwargs.items()}
             attribute_pairs = [
@@ -569,6 +1214,8 @@ class FileParser(object):
                         method_kwargs[key] = AccessKind(value)
                     else:
                         method_kwargs[key] = AccessKind.private
+                elif key in [role.value for role in TestRole] and value is True:
+                    method_kwargs["test_role"] = TestRole(key)
             for method_node in method_captures.get("methods", []):
                 method = self._parse_function(
                     method_node, config, method=True, parent=object, **method_kwargs
@@ -608,7 +1255,7 @@ class FileParser(object):
                              otherwise it is the parsed value which can be a boolean or a string.
         """
         self._node = node
-        captures = ATTRIBUTE_QUERY.captures(node)
+        captures = self._queries["ATTRIBUTE_QUERY"].captures(node)
 
         key = self._first_from_capture(captures, "name")
         if TYPE_CHECKING:
@@ -623,7 +1270,12 @@ class FileParser(object):
         return (key, value)
 
     def _parse_function(
-        self, node: Node, config: ParserConfig, method: bool = False, **kwargs: Any
+        self,
+        node: Node,
+        config: ParserConfig,
+        method: bool = False,
+        name_from_declaration: bool = False,
+        **kwargs: Any,
     ) -> Function:
         """
         Parse a function node and return a Function object.
@@ -632,6 +1284,9 @@ class FileParser(object):
             node (Node): The node representing the function in the syntax tree.
             config: Configuration object controlling parser behavior.
             method (bool, optional): Whether the function is a method. Defaults to False.
+            name_from_declaration (bool, optional): Whether the function is named by its
+                declaration, like a local function, rather than by its file. Methods are always
+                named by their declaration. Defaults to False.
             **kwargs: Additional keyword arguments to pass to the Function object.
 
         Returns:
@@ -642,7 +1297,7 @@ class FileParser(object):
 
         """
         self._node = node
-        captures: dict = FUNCTION_QUERY.matches(node)[0][1]
+        captures: dict = self._queries["FUNCTION_QUERY"].matches(node)[0][1]
 
         input_names = self._decode_from_capture(captures, "input")
         arguments: dict = (
@@ -660,11 +1315,19 @@ class FileParser(object):
             if output_names
             else {}
         )
-        if method:
-            function_name = self._first_from_capture(captures, "name")
-        else:
+        # Only the main function of a file is named after the file.
+        main = not (method or name_from_declaration)
+        if main:
             function_name = self.filepath.stem
+        else:
+            function_name = self._first_from_capture(captures, "name")
 
+        return_sites, trailing_return = _return_sites(node)
+        error_ids, warning_ids = _diagnostic_ids(node, self.encoding)
+        try_catch_count, has_empty_catch, calls_error = _exception_handling(node)
+        assigns_input_fields = _assigns_input_fields(
+            node, input_names, output_names, self.encoding
+        )
         object: Function = Function(
             function_name,
             lineno=node.range.start_point.row + 1,
@@ -673,16 +1336,30 @@ class FileParser(object):
             docstring=self._comment_docstring(captures.get("docstring", None)),
             getter="getter" in captures,
             setter="setter" in captures,
+            return_sites=return_sites,
+            trailing_return=trailing_return,
+            error_ids=error_ids,
+            warning_ids=warning_ids,
+            try_catch_count=try_catch_count,
+            has_empty_catch=has_empty_catch,
+            calls_error=calls_error,
+            assigns_input_fields=assigns_input_fields,
+            has_arguments_block=bool(captures.get("arguments")),
+            nargin_sites=_nargin_sites(node),
+            variables=_variables(node, self.encoding),
+            is_function_test=main and _is_function_test(node),
             node=node,
             **kwargs,
         )
 
         captures_arguments = [
-            ARGUMENTS_QUERY.captures(node) for node in captures.get("arguments", [])
+            self._queries["ARGUMENTS_QUERY"].captures(node)
+            for node in captures.get("arguments", [])
         ]
         for capture_arguments in captures_arguments:
             attributes = self._decode_from_capture(capture_arguments, "attributes")
             is_input = attributes is None or "Input" in attributes or "Output" not in attributes
+            repeating = "Repeating" in attributes
 
             arguments_items = _sort_nodes(capture_arguments["arguments_items"])
 
@@ -698,14 +1375,16 @@ class FileParser(object):
                         argument.docstring = docstring
                     continue
 
-                capture_argument = PROPERTY_QUERY.captures(arglist_node)
+                capture_argument = self._queries["PROPERTY_QUERY"].captures(arglist_node)
                 arg_name = self._first_from_capture(capture_argument, "name")
 
                 if "options" in capture_argument:
                     options_name = self._first_from_capture(capture_argument, "options")
                     arguments.pop(options_name, None)
                     argument = arguments[arg_name] = Argument(
-                        arg_name, kind=ArgumentKind.keyword_only, node=arglist_node
+                        arg_name,
+                        kind=ArgumentKind.keyword_only,
+                        name_value_struct=options_name,
                     )
                 else:
                     if is_input:
@@ -718,18 +1397,27 @@ class FileParser(object):
                     else:
                         argument.kind = ArgumentKind.positional_only
 
+                argument.repeating = repeating
+                argument.node = arglist_node
+                argument.lineno = arglist_node.start_point.row + 1
+                argument.endlineno = arglist_node.end_point.row + 1
+
                 # Attach pending docstring if in before mode
                 if docstring is not None and config.docstring_before_arguments:
                     argument.docstring = docstring
 
                 if "dimensions" in capture_argument:
-                    argument.dimensions = self._decode_from_capture(capture_argument, "dimensions")
+                    argument.dimensions = _dimensions(
+                        self._decode_from_capture(capture_argument, "dimensions")
+                    )
 
                 if "type" in capture_argument:
                     argument.type = Expr(capture_argument["type"], self.encoding)
 
                 if "validators" in capture_argument:
-                    argument.validators = Expr(capture_argument["validators"], self.encoding)
+                    argument.validators = parse_validators(
+                        self._decode(capture_argument["validators"][0])
+                    )
 
                 if "default" in capture_argument:
                     argument.default = Expr(capture_argument["default"], self.encoding)
@@ -756,6 +1444,9 @@ class FileParser(object):
         """
         Decode elements from a capture dictionary based on a specified key.
 
+        Captured elements are short identifiers, attributes and sizes that repeat across files, so
+        the decoded strings are interned to share a single copy in large collections.
+
         Args:
             capture: A dictionary where the keys are strings and the values are lists of Node objects.
             key: The key to look for in the capture dictionary.
@@ -766,7 +1457,7 @@ class FileParser(object):
         if key not in capture:
             return []
         else:
-            return [self._decode(element) for element in capture[key]]
+            return [sys.intern(self._decode(element)) for element in capture[key]]
 
     def _first_from_capture(self, capture: dict[str, list[Node]], key: str) -> str:
         """
@@ -1,11 +1,55 @@
 """Configuration for pytest."""
 
 from pathlib import Path
+from unittest.mock import Mock
 
 import pytest
 
+from maxx.objects import Class, Function, Object, Property
+
 
 @pytest.fixture
 def test_files_dir():
     """Return the path to the test files directory."""
     return Path(__file__).parent / "files"
+
+
+@pytest.fixture
+def write_function():
+    """Return a function writing a function file named after the function."""
+
+    def write(directory: Path, name: str, h1: str | None = None) -> Path:
+        directory.mkdir(parents=True, exist_ok=True)
+        path = directory / f"{name}.m"
+        path.write_text(f"function {name}()\n% {h1 or name}\nend\n")
+        return path
+
+    return write
+
+
+@pytest.fixture
+def make_class():
+    """Return a function creating a class with the given bases, methods and properties."""
+
+    def make(name: str, bases: list[str], methods: list[str], properties: list[str]) -> Class:
+        cls = Class(name, bases=bases, filepath=Path(f"/path/to/{name}.m"))
+        for method in methods:
+            cls.members[method] = Function(method, parent=cls)
+        for prop in properties:
+            cls.members[prop] = Property(prop, parent=cls)
+        return cls
+
+    return make
+
+
+@pytest.fixture
+def mock_collection():
+    """Return a function creating a mock paths collection resolving the given objects by name."""
+
+    def make(*objects: Object) -> Mock:
+        mapping = {obj.name: obj for obj in objects}
+        collection = Mock()
+        collection.get_member.side_effect = lambda name: mapping.get(name)
+        return collection
+
+    return make
@@ -0,0 +1,12 @@
+function result = early_return(x)
+% Function with an early return
+%
+% Returns zero for negative input, and the input otherwise.
+
+    result = 0;
+    if x < 0
+        return
+    end
+    result = x;
+    return
+end
@@ -1,11 +1,16 @@
 """Tests for the collect module."""
 
+import os
+import sys
 from pathlib import Path
 
 import pytest
 
-from maxx.collection import LinesCollection, PathsCollection
-from maxx.objects import Class, ClassFolder, Function, Script
+from maxx.collection import LinesCollection, PathsCollection, parse_directory, parse_files
+from maxx.enums import CacheStrategy, Kind, ParseIssueKind, PathNormalization
+from maxx.exceptions import AmbiguousNameError
+from maxx.objects import Class, ClassFolder, Function, Namespace, Script
+from maxx.treesitter import FileParser
 
 # Base directory for test files
 TEST_FILES_DIR = Path(__file__).parent / "files"
@@ -98,6 +103,7 @@ class TestPathsCollection:
             "GetterSetterClass",
             "block_comment_function",
             "malformed",
+            "early_return",
             "pragma_function",
             "multiline_docstring",
             "complex_block_comment",
@@ -202,6 +208,28 @@ class TestPathsCollection:
             "ClassFolder.analyze"
         )
 
+    def test_source_path_function(self):
+        """Test the source path of a function file."""
+        assert self.paths_collection.source_path("test_function") == (
+            TEST_FILES_DIR / "test_function.m"
+        )
+
+    def test_source_path_classfolder(self):
+        """Test that the source path of a class folder is its class definition file."""
+        assert self.paths_collection.source_path("ClassFolder") == (
+            TEST_FILES_DIR / "@ClassFolder" / "ClassFolder.m"
+        )
+
+    def test_source_path_classfolder_method(self):
+        """Test the source path of a method defined in its own file."""
+        assert self.paths_collection.source_path("ClassFolder.analyze") == (
+            TEST_FILES_DIR / "@ClassFolder" / "analyze.m"
+        )
+
+    def test_source_path_nonexistent(self):
+        """Test that unknown identifiers have no source path."""
+        assert self.paths_collection.source_path("DoesNotExist") is None
+
     def test_function_argument_namespace(self):
         """Test that function arguments with namespaces are properly parsed."""
         func = self.paths_collection.get_member("plot_axes")
@@ -215,7 +243,7 @@ class TestPathsCollection:
             docstring_sections[0].value == "adds the gradient to the plot in axes with handle `ax`."
         )
         assert arg.name == "ax"
-        assert arg.dimensions == ["1", "1"]
+        assert arg.dimensions == [1, 1]
         assert str(arg.type) == "matlab.graphics.axis.Axes"
         assert str(arg.default) == "gca"
 
@@ -385,3 +413,676 @@ class TestPathsCollectionAdvanced:
         assert len(items) > 0
         # Items should be (key, value) tuples
         assert all(isinstance(item, tuple) and len(item) == 2 for item in items)
+
+
+class TestPathsCollectionPrecedence:
+    """Tests for name conflict resolution following the search path order."""
+
+    @pytest.fixture
+    def conflicting_paths(self, tmp_path):
+        """Create two directories that both define `MyFunc`."""
+        first = tmp_path / "first"
+        second = tmp_path / "second"
+        for directory in (first, second):
+            directory.mkdir()
+            (directory / "MyFunc.m").write_text(
+                f"function MyFunc()\n% Defined in {directory.name}\nend\n"
+            )
+        return first, second
+
+    def test_first_path_wins(self, conflicting_paths):
+        """Test that the definition from the earlier-listed path is resolved."""
+        first, second = conflicting_paths
+        collection = PathsCollection([first, second])
+        assert collection.get_path("MyFunc") == first / "MyFunc.m"
+        assert collection.precedence_order("MyFunc") == [first / "MyFunc.m", second / "MyFunc.m"]
+
+    def test_addpath_to_start_shadows(self, conflicting_paths):
+        """Test that a path added to the start of the search path takes precedence."""
+        first, second = conflicting_paths
+        collection = PathsCollection([first])
+        collection.addpath(second)
+        assert collection.precedence_order("MyFunc") == [second / "MyFunc.m", first / "MyFunc.m"]
+
+    def test_rmpath_unshadows(self, conflicting_paths):
+        """Test that removing a path restores the shadowed definition."""
+        first, second = conflicting_paths
+        collection = PathsCollection([first, second])
+        collection.rmpath(first)
+        assert collection.precedence_order("MyFunc") == [second / "MyFunc.m"]
+
+    def test_precedence_order_unknown(self, conflicting_paths):
+        """Test that an unknown identifier has no source files."""
+        collection = PathsCollection(list(conflicting_paths))
+        assert collection.precedence_order("DoesNotExist") == []
+
+    def test_private_member(self, tmp_path, write_function):
+        """Test that only the functions in the private folder of a directory are found."""
+        write_function(tmp_path, "helper")
+        write_function(tmp_path / "private", "helper")
+        collection = PathsCollection([tmp_path])
+
+        private = collection.private_member(tmp_path, "helper")
+        assert private.filepath == tmp_path / "private" / "helper.m"
+        assert collection.private_member(str(tmp_path), "missing") is None
+        assert collection.private_member(tmp_path / "private", "helper") is None
+
+
+class TestPathsCollectionRemove:
+    """Tests for removing objects from a collection."""
+
+    def test_remove(self, tmp_path, write_function):
+        """Test that a removed object is no longer resolved, and unshadows other definitions."""
+        write_function(tmp_path / "first", "func")
+        write_function(tmp_path / "second", "func")
+        write_function(tmp_path / "first", "other")
+        collection = PathsCollection([tmp_path / "first", tmp_path / "second"])
+
+        removed = collection.remove("func")
+
+        assert removed.filepath == tmp_path / "first" / "func.m"
+        assert collection.precedence_order("func") == [tmp_path / "second" / "func.m"]
+        assert "other" in collection
+        assert collection.remove("missing") is None
+
+    def test_remove_path(self, tmp_path, write_function):
+        """Test that the objects defined in a file or directory are removed."""
+        write_function(tmp_path, "func")
+        write_function(tmp_path / "+ns", "helper")
+        (tmp_path / "@MyClass").mkdir()
+        (tmp_path / "@MyClass" / "MyClass.m").write_text("classdef MyClass\nend\n")
+        collection = PathsCollection([tmp_path])
+
+        assert collection.remove_path(tmp_path / "func.m") == ["func"]
+        assert "func" not in collection
+        assert collection.remove_path(tmp_path / "@MyClass" / "MyClass.m") == ["MyClass"]
+        assert "MyClass" not in collection
+        assert set(collection.remove_path(tmp_path / "+ns")) == {"+ns", "ns.helper"}
+        assert collection.members == {}
+        assert list(collection._path) == [tmp_path]
+
+    def test_remove_path_namespace(self, tmp_path, write_function):
+        """Test that the namespace of a removed file no longer lists its object."""
+        write_function(tmp_path / "+ns", "helper")
+        write_function(tmp_path / "+ns", "other")
+        collection = PathsCollection([tmp_path])
+        namespace = collection["+ns"]
+
+        assert collection.remove_path(tmp_path / "+ns" / "helper.m") == ["ns.helper"]
+        assert collection["+ns"] is not namespace
+        assert set(collection["+ns"].members) == {"other"}
+
+        collection.addpath(tmp_path)
+        assert set(collection["+ns"].members) == {"helper", "other"}
+
+
+class TestParseHelpers:
+    """Tests for the convenience functions creating a collection."""
+
+    def test_parse_directory(self, tmp_path, write_function):
+        """Test that a directory is collected, recursively if requested."""
+        write_function(tmp_path, "func")
+        write_function(tmp_path / "sub", "nested")
+
+        assert set(parse_directory(tmp_path).members) == {"func"}
+        assert set(parse_directory(str(tmp_path), recursive=True).members) == {"func", "nested"}
+
+    def test_parse_files(self, tmp_path, write_function):
+        """Test that only the listed files are collected, named as on the MATLAB path."""
+        write_function(tmp_path, "func")
+        write_function(tmp_path, "other")
+        write_function(tmp_path / "+ns", "helper")
+        write_function(tmp_path / "+ns", "unused")
+        write_function(tmp_path / "+empty", "skipped")
+
+        collection = parse_files([tmp_path / "func.m", tmp_path / "+ns" / "helper.m"])
+
+        assert set(collection.members) == {"func", "+ns", "ns.helper"}
+        assert set(collection["+ns"].members) == {"helper"}
+        assert list(collection._path) == [tmp_path]
+        assert {file.path for file in collection.stats.files} == {
+            tmp_path / "func.m",
+            tmp_path / "+ns" / "helper.m",
+        }
+
+    def test_parse_files_class_folder(self, tmp_path, write_function):
+        """Test that the methods of a class folder are collected with its class definition file."""
+        (tmp_path / "@Shape").mkdir()
+        (tmp_path / "@Shape" / "Shape.m").write_text("classdef Shape\nend\n")
+        write_function(tmp_path / "@Shape", "area")
+        write_function(tmp_path, "other")
+
+        collection = parse_files([tmp_path / "@Shape" / "Shape.m"])
+
+        assert {file.path for file in collection.stats.files} == {
+            tmp_path / "@Shape" / "Shape.m",
+            tmp_path / "@Shape" / "area.m",
+        }
+
+
+class TestPathsCollectionRefresh:
+    """Tests for parsing changed files again."""
+
+    @staticmethod
+    def _touch(path, offset):
+        """Set the modification time of a file relative to its current one, in seconds."""
+        stat = path.stat()
+        os.utime(path, ns=(stat.st_atime_ns, stat.st_mtime_ns + offset * 10**9))
+
+    def test_refresh_mtime(self, tmp_path):
+        """Test that files with a new modification time are parsed again."""
+        path = tmp_path / "func.m"
+        path.write_text("function func()\n% Old\nend\n")
+        (tmp_path / "other.m").write_text("function other()\n% Other\nend\n")
+        collection = PathsCollection([tmp_path])
+        func, other = collection["func"], collection["other"]
+
+        assert collection.refresh() == []
+        path.write_text("function func()\n% New\nend\n")
+        self._touch(path, 10)
+
+        assert collection.refresh() == [path]
+        assert collection["func"] is not func
+        assert collection["other"] is other
+        assert collection.refresh() == []
+
+    def test_refresh_content_hash(self, tmp_path):
+        """Test that the content hash detects changes regardless of modification times."""
+        path = tmp_path / "func.m"
+        path.write_text("function func()\n% Old\nend\n")
+        collection = PathsCollection([tmp_path], cache_strategy=CacheStrategy.content_hash)
+
+        self._touch(path, 10)
+        assert collection.refresh() == []
+        path.write_text("function func()\n% New\nend\n")
+        self._touch(path, -10)
+        assert collection.refresh() == [path]
+
+    def test_refresh_both(self, tmp_path):
+        """Test that touched files whose content is unchanged are not parsed again."""
+        path = tmp_path / "func.m"
+        path.write_text("function func()\n% Old\nend\n")
+        collection = PathsCollection([tmp_path], cache_strategy=CacheStrategy.both)
+
+        self._touch(path, 10)
+        assert collection.refresh() == []
+        path.write_text("function func()\n% New\nend\n")
+        self._touch(path, 10)
+        assert collection.refresh() == [path]
+
+    def test_refresh_namespace(self, tmp_path):
+        """Test that the namespace of a changed file is collected again."""
+        (tmp_path / "+ns").mkdir()
+        path = tmp_path / "+ns" / "helper.m"
+        path.write_text("function helper()\n% Old\nend\n")
+        collection = PathsCollection([tmp_path])
+        namespace = collection["+ns"]
+
+        self._touch(path, 10)
+        assert collection.refresh() == [path]
+        assert collection["+ns"] is not namespace
+        assert collection["+ns"].members["helper"] is collection["ns.helper"]
+
+    def test_refresh_deleted(self, tmp_path):
+        """Test that the objects of deleted files are removed."""
+        path = tmp_path / "func.m"
+        path.write_text("function func()\n% Function\nend\n")
+        collection = PathsCollection([tmp_path])
+
+        path.unlink()
+        assert collection.refresh() == [path]
+        assert "func" not in collection
+
+    def test_reload_file(self, tmp_path):
+        """Test that a single file is parsed again, returning its previous object."""
+        path = tmp_path / "func.m"
+        path.write_text("function func()\n% Old\nend\n")
+        (tmp_path / "other.m").write_text("function other()\n% Other\nend\n")
+        collection = PathsCollection([tmp_path])
+        func, other = collection["func"], collection["other"]
+
+        assert collection.reload_file(path) is func
+        assert collection["func"] is not func
+        assert collection["other"] is other
+        assert collection.reload_file(tmp_path / "missing.m") is None
+
+        reloaded = collection["func"]
+        path.unlink()
+        assert collection.reload_file(str(path)) is reloaded
+        assert "func" not in collection
+
+    def test_reload_file_deleted_namespace(self, tmp_path, write_function):
+        """Test that the namespace of a deleted file no longer lists its object."""
+        write_function(tmp_path / "+ns", "helper")
+        write_function(tmp_path / "+ns", "other")
+        collection = PathsCollection([tmp_path])
+        helper, namespace = collection["ns.helper"], collection["+ns"]
+
+        (tmp_path / "+ns" / "helper.m").unlink()
+        assert collection.reload_file(tmp_path / "+ns" / "helper.m") is helper
+        assert collection["+ns"] is not namespace
+        assert set(collection["+ns"].members) == {"other"}
+
+
+class TestPathsCollectionIgnoreFiles:
+    """Tests for leaving out paths matched by ignore files."""
+
+    FUNCTION = "function {name}()\n% Function {name}\nend\n"
+
+    @pytest.fixture
+    def repository(self, tmp_path):
+        """Create a git repository with ignored .m files."""
+        (tmp_path / ".git").mkdir()
+        (tmp_path / ".gitignore").write_text("build/\n# generated\n*_generated.m\n")
+        for relative in [
+            "src/kept.m",
+            "src/code_generated.m",
+            "src/local.m",
+            "build/output.m",
+            "+ns/member.m",
+            "+ns/member_generated.m",
+        ]:
+            path = tmp_path / relative
+            path.parent.mkdir(parents=True, exist_ok=True)
+            path.write_text(self.FUNCTION.format(name=path.stem))
+        (tmp_path / "src" / ".maxxignore").write_text("local.m\n")
+        return tmp_path
+
+    def test_ignored_paths_not_collected(self, repository):
+        """Test that ignored files and directories do not appear in the collection."""
+        collection = PathsCollection([repository], recursive=True)
+        keys = set(collection.members.keys())
+        assert "kept" in keys
+        assert "ns.member" in keys
+        assert "code_generated" not in keys
+        assert "local" not in keys
+        assert "output" not in keys
+        assert "ns.member_generated" not in keys
+
+    def test_ignored_namespace_member(self, repository):
+        """Test that ignored files in a namespace are not namespace members."""
+        collection = PathsCollection([repository], recursive=True)
+        namespace = collection["+ns"]
+        assert namespace is not None
+        assert set(namespace.members) == {"member"}
+
+    def test_respect_gitignore_disabled(self, repository):
+        """Test that ignore files can be disregarded."""
+        collection = PathsCollection([repository], recursive=True, respect_gitignore=False)
+        keys = set(collection.members.keys())
+        assert {"kept", "code_generated", "local", "output"} <= keys
+
+    def test_explicit_path_bypasses_ignore(self, repository):
+        """Test that an explicitly added path is collected even if it is ignored."""
+        collection = PathsCollection([repository / "build"])
+        assert "output" in collection.members
+
+    def test_outside_repository(self, tmp_path):
+        """Test that ignore files are not respected by default outside a git repository."""
+        (tmp_path / ".gitignore").write_text("*.m\n")
+        (tmp_path / "func.m").write_text(self.FUNCTION.format(name="func"))
+        assert "func" in PathsCollection([tmp_path]).members
+        assert "func" not in PathsCollection([tmp_path], respect_gitignore=True).members
+
+
+class TestPathsCollectionMaxDepth:
+    """Tests for limiting the depth of the collected directories."""
+
+    def test_max_depth(self, tmp_path):
+        """Test that only the requested number of directory levels is collected."""
+        (tmp_path / "a" / "b").mkdir(parents=True)
+        (tmp_path / "+ns").mkdir()
+        (tmp_path / "top.m").write_text("function top()\nend\n")
+        (tmp_path / "+ns" / "helper.m").write_text("function helper()\nend\n")
+        (tmp_path / "a" / "middle.m").write_text("function middle()\nend\n")
+        (tmp_path / "a" / "b" / "deep.m").write_text("function deep()\nend\n")
+
+        def functions(collection):
+            members = collection.members.items()
+            return {name for name, alias in members if alias.kind is Kind.FUNCTION}
+
+        assert functions(PathsCollection([tmp_path], max_depth=1)) == {"top", "ns.helper"}
+        assert functions(PathsCollection([tmp_path], max_depth=2)) == {"top", "ns.helper", "middle"}
+        assert functions(parse_directory(tmp_path, recursive=True, max_depth=2)) == {
+            "top",
+            "ns.helper",
+            "middle",
+        }
+        assert functions(PathsCollection([tmp_path], recursive=True)) == {
+            "top",
+            "ns.helper",
+            "middle",
+            "deep",
+        }
+
+
+class TestPathsCollectionMaxFileSize:
+    """Tests for skipping files above a maximum size."""
+
+    @pytest.fixture
+    def folder(self, tmp_path):
+        """Create a folder with a small function and a large generated lookup table."""
+        (tmp_path / "small.m").write_text("function small()\n% Small function\nend\n")
+        rows = "\n".join(f"    {index}, {index * 2};" for index in range(5000))
+        (tmp_path / "lookup.m").write_text(f"function t = lookup()\nt = [\n{rows}\n];\nend\n")
+        (tmp_path / "+ns").mkdir()
+        (tmp_path / "+ns" / "lookup.m").write_text((tmp_path / "lookup.m").read_text())
+        return tmp_path
+
+    def test_large_files_skipped(self, folder):
+        """Test that files above the size limit are skipped and reported."""
+        collection = PathsCollection([folder], max_file_size=10_000)
+
+        assert "small" in collection.members
+        assert "lookup" not in collection.members
+        assert "ns.lookup" not in collection.members
+        assert set(collection.skipped) == {folder / "lookup.m", folder / "+ns" / "lookup.m"}
+        assert "exceeds the maximum of 10000 bytes" in collection.skipped[folder / "lookup.m"]
+
+    def test_skipped_namespace_member(self, folder):
+        """Test that skipped files are not namespace members."""
+        collection = PathsCollection([folder], max_file_size=10_000)
+        namespace = collection["+ns"]
+        assert namespace is not None
+        assert len(namespace.members) == 0
+
+    def test_no_limit(self, folder):
+        """Test that files of any size are collected by default."""
+        collection = PathsCollection([folder])
+
+        assert "lookup" in collection.members
+        assert collection.skipped == {}
+
+
+class TestPathsCollectionWalk:
+    """Tests for walking all objects in a collection."""
+
+    def test_walk(self, tmp_path):
+        """Test that walk yields every object once, with its dotted path."""
+        (tmp_path / "top.m").write_text("function top()\n% Top function\nend\n")
+        (tmp_path / "+ns").mkdir()
+        (tmp_path / "+ns" / "helper.m").write_text("function helper()\n% Helper\nend\n")
+        (tmp_path / "+ns" / "Shape.m").write_text(
+            "classdef Shape\n"
+            "    properties\n"
+            "        Area\n"
+            "    end\n"
+            "    methods\n"
+            "        function draw(obj)\n"
+            "        end\n"
+            "    end\n"
+            "end\n"
+        )
+        collection = PathsCollection([tmp_path])
+
+        paths = [path for path, _ in collection.walk()]
+        assert len(paths) == len(set(paths))
+        assert {"top", "+ns", "ns.helper", "ns.Shape"} <= set(paths)
+        assert {"ns.Shape.Area", "ns.Shape.draw"} <= set(paths)
+        for path, object in collection.walk():
+            assert object.path == path
+
+
+class TestPathsCollectionTolerant:
+    """Tests for collecting files that cannot be read or parsed."""
+
+    @pytest.fixture
+    def folder(self, tmp_path, monkeypatch):
+        """Create a folder with a valid function and a function that cannot be read."""
+        (tmp_path / "good.m").write_text("function good()\n% Good function\nend\n")
+        (tmp_path / "bad.m").write_text("function bad()\nend\n")
+        set_filepath = FileParser.set_filepath
+
+        def unreadable(self, filepath):
+            if filepath.name == "bad.m":
+                raise PermissionError(f"Permission denied: '{filepath}'")
+            set_filepath(self, filepath)
+
+        monkeypatch.setattr(FileParser, "set_filepath", unreadable)
+        return tmp_path
+
+    def test_unreadable_file(self, folder):
+        """Test that an unreadable file gives an issue instead of an error."""
+        collection = PathsCollection([folder])
+
+        assert isinstance(collection["good"], Function)
+        assert collection["bad"] is None
+        assert set(collection.issues) == {folder / "bad.m"}
+        issue = collection.issues[folder / "bad.m"][0]
+        assert issue.kind is ParseIssueKind.io
+        assert "Permission denied" in issue.message
+
+    def test_statistics(self, folder):
+        """Test that the statistics list the files that failed."""
+        (folder / "large.m").write_text("function large()\n" + "% Padding\n" * 100 + "end\n")
+        statistics = PathsCollection([folder], max_file_size=500).statistics()
+
+        assert statistics.total_objects == 1
+        assert statistics.by_kind == {Kind.FUNCTION: 1}
+        assert statistics.files_scanned == 2
+        assert statistics.files_skipped == 1
+        assert [path for path, _ in statistics.parse_errors] == [folder / "bad.m"]
+        assert "Permission denied" in statistics.parse_errors[0][1]
+        assert statistics.files_parsed == 1
+
+    def test_strict(self, folder):
+        """Test that errors are raised when the collection is not tolerant."""
+        with pytest.raises(PermissionError):
+            PathsCollection([folder], tolerant=False)
+
+    def test_syntax_error(self, tmp_path):
+        """Test that syntax errors in a file are recorded as issues."""
+        (tmp_path / "broken.m").write_text(
+            "function result = broken(x\n% Missing closing parenthesis\nresult = x + 1;\nend\n"
+        )
+        collection = PathsCollection([tmp_path])
+        collection["broken"]
+
+        issues = collection.issues[tmp_path / "broken.m"]
+        assert issues
+        assert all(issue.kind is ParseIssueKind.syntax for issue in issues)
+        assert all(issue.lineno is not None for issue in issues)
+
+
+class TestPathsCollectionAncestors:
+    """Tests for iterating over the namespaces and folders containing an object."""
+
+    def test_namespaces(self, tmp_path):
+        """Test that nested namespaces are yielded from the innermost to the outermost."""
+        (tmp_path / "+outer" / "+inner").mkdir(parents=True)
+        (tmp_path / "+outer" / "+inner" / "func.m").write_text("function func()\nend\n")
+        collection = PathsCollection([tmp_path])
+
+        ancestors = list(collection.ancestors("outer.inner.func"))
+
+        assert [ancestor.name for ancestor in ancestors] == ["inner", "outer"]
+        assert all(isinstance(ancestor, Namespace) for ancestor in ancestors)
+        assert [ancestor.name for ancestor in collection.ancestors("+outer.inner")] == ["outer"]
+
+    def test_folders(self, tmp_path):
+        """Test that the folders of a recursively added path are yielded."""
+        (tmp_path / "sub").mkdir()
+        (tmp_path / "sub" / "func.m").write_text("function func()\nend\n")
+        (tmp_path / "top.m").write_text("function top()\nend\n")
+        collection = PathsCollection([tmp_path], recursive=True)
+
+        assert [folder.name for folder in collection.ancestors("func")] == ["/sub"]
+        assert list(collection.ancestors("top")) == []
+        assert list(collection.ancestors("missing")) == []
+
+
+class TestPathsCollectionResolveQualified:
+    """Tests for resolving dotted names through namespaces."""
+
+    def test_resolve_qualified(self, tmp_path):
+        """Test that each part of a dotted name is looked up in the members of the previous one."""
+        (tmp_path / "+ns" / "+sub").mkdir(parents=True)
+        (tmp_path / "+ns" / "+sub" / "MyClass.m").write_text("classdef MyClass\nend\n")
+        (tmp_path / "+ns" / "func.m").write_text("function func()\nend\n")
+        (tmp_path / "top.m").write_text("function top()\nend\n")
+        collection = PathsCollection([tmp_path])
+
+        cls = collection.resolve_qualified("ns.sub.MyClass")
+        assert isinstance(cls, Class)
+        assert cls.filepath == tmp_path / "+ns" / "+sub" / "MyClass.m"
+        assert isinstance(collection.resolve_qualified("ns.sub"), Namespace)
+        assert collection.resolve_qualified("ns.func").name == "func"
+        assert collection.resolve_qualified("top").name == "top"
+        assert collection.resolve_qualified("ns.missing") is None
+        assert collection.resolve_qualified("missing.sub.MyClass") is None
+
+
+class TestPathsCollectionTests:
+    """Tests for finding the tests in a collection."""
+
+    def test_tests(self, tmp_path):
+        """Test that test classes and function-based tests are found."""
+        (tmp_path / "solver.m").write_text("function solver()\n% Solver\nend\n")
+        (tmp_path / "SolverTest.m").write_text(
+            "classdef SolverTest < matlab.unittest.TestCase\n"
+            "    methods (Test)\n"
+            "        function testSolve(testCase)\n"
+            "        end\n"
+            "    end\n"
+            "end\n"
+        )
+        (tmp_path / "solverFunctionTest.m").write_text(
+            "function tests = solverFunctionTest\ntests = functiontests(localfunctions);\nend\n"
+        )
+        (tmp_path / "+ns").mkdir()
+        (tmp_path / "+ns" / "ShapeTest.m").write_text(
+            "classdef ShapeTest < matlab.unittest.TestCase\nend\n"
+        )
+        collection = PathsCollection([tmp_path])
+
+        assert set(collection.tests()) == {"SolverTest", "solverFunctionTest", "ns.ShapeTest"}
+
+
+class TestPathsCollectionStats:
+    """Tests for the statistics of collecting and parsing files."""
+
+    def test_stats(self, tmp_path):
+        """Test that walking and parsing are recorded."""
+        content = "function func()\n% Function\nend\n"
+        (tmp_path / "func.m").write_text(content)
+        (tmp_path / "other.m").write_text(content)
+        collection = PathsCollection([tmp_path])
+        collection["func"]
+        collection["other"]
+
+        stats = collection.stats
+        assert stats.walk_time >= 0
+        assert {file.path for file in stats.files} == {tmp_path / "func.m", tmp_path / "other.m"}
+        assert stats.bytes_parsed == 2 * len(content)
+        assert stats.total_time >= stats.parse_time > 0
+        assert len(stats.slowest(1)) == 1
+
+        stats.files.clear()
+        assert collection.stats.files_parsed == 2
+
+
+class TestPathsCollectionAliases:
+    """Tests for resolving identifiers through path aliases."""
+
+    def test_alias_resolves_from_mapped_directory(self, tmp_path):
+        """Test that an aliased identifier resolves to the shadowed definition."""
+        for name in ("lib1", "lib2"):
+            (tmp_path / name).mkdir()
+            (tmp_path / name / "helper.m").write_text(f"function helper()\n% {name}\nend\n")
+        collection = PathsCollection([tmp_path / "lib1"], aliases={"lib2": tmp_path / "lib2"})
+
+        assert collection["helper"].filepath == tmp_path / "lib1" / "helper.m"
+        assert collection["lib2.helper"].filepath == tmp_path / "lib2" / "helper.m"
+        assert collection["lib1.helper"] is None
+        assert "lib2.missing" not in collection
+
+    def test_load_pathdef(self, tmp_path):
+        """Test reading the directories of a pathdef.m file."""
+        pathdef = tmp_path / "pathdef.m"
+        pathdef.write_text(
+            "function p = pathdef\n"
+            "p = [...\n"
+            "%%% BEGIN ENTRIES %%%\n"
+            "     '/work/mylib:', ...\n"
+            "     matlabroot,'/toolbox/matlab/general:', ...\n"
+            "     '/other/mylib:', ...\n"
+            "     '/work/tools;', ...\n"
+            "%%% END ENTRIES %%%\n"
+            "     ...\n"
+            "];\n"
+        )
+
+        assert PathsCollection.load_pathdef(pathdef) == {
+            "mylib": Path("/work/mylib"),
+            "tools": Path("/work/tools"),
+        }
+        aliases = PathsCollection.load_pathdef(pathdef, matlabroot="/opt/matlab")
+        assert aliases["general"] == Path("/opt/matlab/toolbox/matlab/general")
+
+
+class TestPathsCollectionPathNormalization:
+    """Tests for normalizing added paths and case-insensitive lookups."""
+
+    def test_default(self, tmp_path, write_function):
+        """Test that lookups are case-insensitive by default on Windows only."""
+        write_function(tmp_path, "Foo")
+        collection = PathsCollection([tmp_path])
+
+        if sys.platform == "win32":
+            assert PathNormalization.default() is PathNormalization.case_insensitive
+            assert collection["foo"] is not None
+        else:
+            assert PathNormalization.default() is PathNormalization.none
+            assert collection["foo"] is None
+
+    def test_resolve(self, tmp_path, write_function):
+        """Test that different spellings of a directory are collected once."""
+        write_function(tmp_path / "lib", "func")
+        collection = PathsCollection(
+            [tmp_path / "lib", tmp_path / "lib" / ".." / "lib"],
+            path_normalization=PathNormalization.resolve,
+        )
+
+        assert list(collection._path) == [(tmp_path / "lib").resolve()]
+        assert collection["func"].filepath == (tmp_path / "lib" / "func.m").resolve()
+
+        collection.rmpath(tmp_path / "lib" / ".." / "lib")
+        assert "func" not in collection
+
+    def test_case_insensitive(self, tmp_path, write_function):
+        """Test that identifiers fall back to case-insensitive lookups."""
+        write_function(tmp_path / "lib", "Foo")
+        collection = PathsCollection(
+            [tmp_path / "lib"], path_normalization=PathNormalization.case_insensitive
+        )
+
+        assert collection["foo"].name == "Foo"
+        assert collection["FOO"].name == "Foo"
+        assert "bar" not in collection
+
+    def test_local_collection(self, tmp_path, write_function):
+        """Test that local collections follow the settings of their parent."""
+        write_function(tmp_path / "lib", "Foo")
+        collection = PathsCollection(
+            [tmp_path / "lib"],
+            tolerant=False,
+            path_normalization=PathNormalization.case_insensitive,
+        )
+
+        local_collection = collection._local_collections[tmp_path / "lib"]
+        assert local_collection._tolerant is False
+        assert local_collection._path_normalization is PathNormalization.case_insensitive
+
+    def test_case_insensitive_ambiguous(self, tmp_path, write_function):
+        """Test that identifiers that differ only by case are reported as ambiguous."""
+        write_function(tmp_path / "lib1", "Foo")
+        write_function(tmp_path / "lib2", "FOO")
+        collection = PathsCollection(
+            [tmp_path / "lib1", tmp_path / "lib2"],
+            path_normalization=PathNormalization.case_insensitive,
+        )
+
+        assert collection["Foo"].name == "Foo"
+        with pytest.raises(AmbiguousNameError):
+            collection["foo"]
+        assert "foo" not in collection
@@ -0,0 +1,50 @@
+"""Tests for the contents module."""
+
+from maxx.collection import PathsCollection
+from maxx.contents import ContentsMismatch, check_contents_file
+from maxx.enums import ContentsMismatchKind
+
+
+class TestCheckContentsFile:
+    """Test class for checking Contents.m files."""
+
+    def test_mismatches(self, tmp_path, write_function):
+        """Test that missing, stale and outdated entries are reported."""
+        write_function(tmp_path, "listed", "LISTED Do the listed thing.")
+        write_function(tmp_path, "outdated", "OUTDATED Do the new thing.")
+        write_function(tmp_path, "unlisted", "UNLISTED Do another thing.")
+        contents = tmp_path / "Contents.m"
+        contents.write_text(
+            "% MYTOOLBOX Tools for things\n"
+            "% Version 1.0 (R2024a) 01-Jan-2024\n"
+            "%\n"
+            "% Files\n"
+            "%   listed   - Do the listed thing.\n"
+            "%   outdated - Do the old thing.\n"
+            "%   removed  - Do a removed thing.\n"
+        )
+        collection = PathsCollection([tmp_path])
+
+        assert check_contents_file(contents, collection) == [
+            ContentsMismatch(
+                ContentsMismatchKind.description,
+                "outdated",
+                "Do the old thing.",
+                "Do the new thing.",
+            ),
+            ContentsMismatch(ContentsMismatchKind.missing, "unlisted"),
+            ContentsMismatch(ContentsMismatchKind.stale, "removed", "Do a removed thing."),
+        ]
+
+    def test_up_to_date(self, tmp_path, write_function):
+        """Test that a Contents.m file listing every file has no mismatches."""
+        write_function(tmp_path, "func", "func   Compute  it.")
+        contents = tmp_path / "Contents.m"
+        contents.write_text("% Files\n%   func - Compute it.\n")
+
+        assert check_contents_file(contents, PathsCollection([tmp_path])) == []
+
+    def test_str(self):
+        """Test the descriptions of mismatches."""
+        assert str(ContentsMismatch(ContentsMismatchKind.missing, "func")) == "func is not listed"
+        assert "does not exist" in str(ContentsMismatch(ContentsMismatchKind.stale, "func"))
@@ -0,0 +1,109 @@
+"""Tests for the documentation module."""
+
+from pathlib import Path
+
+from maxx.collection import PathsCollection
+from maxx.documentation import generate, to_markdown
+from maxx.enums import ArgumentKind
+from maxx.objects import (
+    Argument,
+    Arguments,
+    Class,
+    Docstring,
+    Function,
+    Namespace,
+    Property,
+)
+
+
+class TestToMarkdown:
+    """Test class for generating Markdown from objects."""
+
+    @staticmethod
+    def _function() -> Function:
+        """Create a function with documented arguments and outputs."""
+        return Function(
+            "scale",
+            filepath=Path("/path/to/scale.m"),
+            docstring=Docstring("Scale a vector.\n\nThe result has the same size as the input."),
+            arguments=Arguments(
+                Argument("x", type="double", docstring=Docstring("Vector | matrix to scale.")),
+                Argument("factor", type="double", default="1", kind=ArgumentKind.optional),
+                Argument("Mode", default='"linear"', kind=ArgumentKind.keyword_only),
+            ),
+            returns=Arguments(Argument("y", docstring=Docstring("The scaled\nvector."))),
+        )
+
+    def test_function(self):
+        """Test the document of a function."""
+        markdown = to_markdown(self._function())
+
+        assert markdown.startswith("# scale\n\n*function*\n")
+        assert '```matlab\nfunction y = scale(x, factor, Mode="linear")\n```' in markdown
+        assert "The result has the same size as the input." in markdown
+        assert "## Arguments" in markdown
+        assert "| `x` | double |  | Vector \\| matrix to scale. |" in markdown
+        assert "| `factor` | double | 1 |  |" in markdown
+        assert "## Returns" in markdown
+        assert "| `y` |  |  | The scaled vector. |" in markdown
+
+    def test_function_multiple_outputs(self):
+        """Test the declaration of a function without inputs and with several outputs."""
+        function = Function(
+            "sizes",
+            filepath=Path("/path/to/sizes.m"),
+            returns=Arguments(Argument("rows"), Argument("cols")),
+        )
+        markdown = to_markdown(function)
+
+        assert "function [rows, cols] = sizes()" in markdown
+        assert "## Arguments" not in markdown
+
+    def test_class(self):
+        """Test the document of a class with properties and methods."""
+        cls = Class(
+            "Shape",
+            bases=["handle", "matlab.mixin.Copyable"],
+            filepath=Path("/path/to/Shape.m"),
+            docstring=Docstring("A shape."),
+        )
+        cls.members["Area"] = Property(
+            "Area", type="double", default="0", docstring=Docstring("The area."), parent=cls
+        )
+        cls.members["draw"] = Function("draw", docstring=Docstring("Draw the shape."), parent=cls)
+        markdown = to_markdown(cls)
+
+        assert markdown.startswith("# Shape\n\n*class*\n")
+        assert "classdef Shape < handle & matlab.mixin.Copyable" in markdown
+        assert "## Properties" in markdown
+        assert "| `Area` | double | 0 | The area. |" in markdown
+        assert "## Methods" in markdown
+        assert "| `draw` | Draw the shape. |" in markdown
+
+    def test_namespace(self):
+        """Test the document of a namespace lists its members."""
+        namespace = Namespace("pkg", filepath=Path("/path/to/+pkg"))
+        namespace.members["scale"] = self._function()
+        markdown = to_markdown(namespace)
+
+        assert "```matlab" not in markdown
+        assert "## Members" in markdown
+        assert "| `scale` | Scale a vector. |" in markdown
+
+
+class TestGenerate:
+    """Test class for writing the documentation of a collection."""
+
+    def test_generate(self, tmp_path):
+        """Test that a document is written for every member of a collection."""
+        source = tmp_path / "src"
+        (source / "+ns").mkdir(parents=True)
+        (source / "top.m").write_text("function top()\n% Top function\nend\n")
+        (source / "+ns" / "helper.m").write_text("function helper()\n% Helper\nend\n")
+        output = tmp_path / "docs"
+
+        written = generate(PathsCollection([source]), output)
+
+        assert {path.name for path in written} == {"top.md", "+ns.md", "ns.helper.md"}
+        assert (output / "top.md").read_text().startswith("# top\n")
+        assert "| `helper` |" in (output / "+ns.md").read_text()
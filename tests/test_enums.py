@@ -0,0 +1,27 @@
+"""Tests for the enums module."""
+
+import pytest
+
+from maxx.enums import AccessKind, ArgumentKind, Kind
+from maxx.exceptions import MaltError
+
+
+class TestStrEnum:
+    """Test class for formatting and parsing enumerations as strings."""
+
+    def test_str(self):
+        """Test that members are formatted as their values."""
+        assert str(Kind.FUNCTION) == "function"
+        assert f"{ArgumentKind.keyword_only}" == "keyword-only"
+        assert "{}".format(AccessKind.private) == "private"
+
+    def test_from_str(self):
+        """Test that members are parsed from their values."""
+        assert Kind.from_str("class") is Kind.CLASS
+        assert ArgumentKind.from_str("keyword-only") is ArgumentKind.keyword_only
+        assert AccessKind.from_str(str(AccessKind.protected)) is AccessKind.protected
+
+    def test_from_str_invalid(self):
+        """Test that invalid values raise a MaltError listing the valid ones."""
+        with pytest.raises(MaltError, match="Invalid AccessKind 'secret', expected one of: public"):
+            AccessKind.from_str("secret")
@@ -0,0 +1,71 @@
+"""Tests for the examples module."""
+
+from griffe import Docstring
+
+from maxx.enums import ParseIssueKind
+from maxx.examples import Example, check_examples, extract_examples
+
+
+class TestExtractExamples:
+    """Test class for extracting the code examples of docstrings."""
+
+    def test_section(self):
+        """Test that the indented lines under an example header form an example."""
+        examples = extract_examples(
+            "Scale a vector.\n"
+            "\n"
+            "Example:\n"
+            "\n"
+            "    y = scale([1 2 3], 2);\n"
+            "    disp(y)\n"
+            "\n"
+            "See also: resize"
+        )
+
+        assert examples == [Example("y = scale([1 2 3], 2);\ndisp(y)", 4)]
+
+    def test_prompts(self):
+        """Test that prompt lines form examples, without their output."""
+        examples = extract_examples(
+            "Examples\n  >> x = 1;\n  >> y = x + 1\n  y =\n      2\n\nText\n>> clear\n"
+        )
+
+        assert examples == [Example("x = 1;\ny = x + 1", 1), Example("clear", 7)]
+
+    def test_no_check(self):
+        """Test that examples with a no-check comment are not checked."""
+        examples = extract_examples("Example:\n    plot(x, y)  % no-check\n")
+
+        assert examples == [Example("plot(x, y)  % no-check", 1, check=False)]
+
+    def test_no_examples(self):
+        """Test docstrings without examples."""
+        assert extract_examples(Docstring("Scale a vector.\n\nExamples are welcome.")) == []
+        assert extract_examples("Example:\nNot indented.") == []
+
+
+class TestCheckExamples:
+    """Test class for checking the syntax of code examples."""
+
+    def test_check(self):
+        """Test that syntax errors are located at their line in the file."""
+        docstring = Docstring(
+            "Scale a vector.\n"
+            "\n"
+            "Example:\n"
+            "    y = scale([1 2 3], 2);\n"
+            "    z = scale(y,\n"
+            "\n"
+            ">> broken(  % no-check\n",
+            lineno=10,
+        )
+
+        issues = check_examples(docstring)
+
+        assert issues
+        assert all(issue.kind is ParseIssueKind.syntax for issue in issues)
+        assert {issue.lineno for issue in issues} <= {13, 14}
+
+    def test_valid(self):
+        """Test that valid examples have no issues."""
+        assert check_examples(Docstring(">> x = ones(3);\n>> y = x';", lineno=1)) == []
@@ -13,6 +13,36 @@ class TestMaltError:
         with pytest.raises(MaltError):
             raise MaltError("Test error")
 
+    def test_malt_error_includes_cause(self):
+        """Test that the message of the underlying cause is included."""
+        with pytest.raises(MaltError) as exc_info:
+            try:
+                raise OSError("file not found")
+            except OSError as error:
+                raise MaltError("could not read file") from error
+
+        assert str(exc_info.value) == "could not read file: file not found"
+
+    def test_malt_error_without_cause(self):
+        """Test that the message is unchanged without a cause."""
+        assert str(MaltError("Test error")) == "Test error"
+
+    def test_with_context(self):
+        """Test wrapping an error with additional context."""
+        error = FilePathError("MyClass")
+        wrapped = error.with_context("while documenting MyClass")
+
+        assert isinstance(wrapped, MaltError)
+        assert wrapped.__cause__ is error
+        assert str(wrapped) == "while documenting MyClass: MyClass"
+
+    def test_with_context_nested(self):
+        """Test that nested contexts show the full chain once."""
+        error = NameResolutionError("foo could not be resolved")
+        wrapped = error.with_context("resolving bases").with_context("collecting MyClass")
+
+        assert str(wrapped) == "collecting MyClass: resolving bases: foo could not be resolved"
+
 
 class TestCyclicAliasError:
     """Test class for CyclicAliasError."""
@@ -1,8 +1,26 @@
 """Tests for the expressions module."""
 
+import json
+from pathlib import Path
 from unittest.mock import Mock
 
-from maxx.expressions import MATHWORKS_DOC_URL, MATLAB_BUILTINS, Expr
+import pytest
+
+from maxx import expressions
+from maxx.enums import ReferenceKind
+from maxx.expressions import (
+    MATHWORKS_DOC_URL,
+    MATLAB_BUILTINS,
+    MATLAB_BUILTINS_ENV,
+    Expr,
+    Reference,
+    Validator,
+    get_builtins,
+    load_builtins,
+    parse_validators,
+    set_builtins,
+)
+from maxx.objects import Class, Property
 
 
 class TestExpr:
@@ -98,3 +116,339 @@ class TestExpr:
 
             # Should find the builtin in the second node
             assert expr.doc == expected_doc
+
+    def test_doc_with_call(self):
+        """Test doc property with a builtin call."""
+        mock_node = Mock()
+        mock_node.text = b"zeros(3)"
+
+        expr = Expr(nodes=[mock_node], encoding="utf-8")  # type: ignore
+
+        assert expr.doc == f"{MATHWORKS_DOC_URL}/{MATLAB_BUILTINS['zeros']}"
+
+    def test_doc_urls(self):
+        """Test doc_urls property returns every builtin reference once."""
+        mock_node = Mock()
+        mock_node.text = b"zeros(3) + ones(3) .* zeros(3) + my_function(x)"
+
+        expr = Expr(nodes=[mock_node], encoding="utf-8")  # type: ignore
+
+        assert expr.doc_urls == [
+            f"{MATHWORKS_DOC_URL}/{MATLAB_BUILTINS['zeros']}",
+            f"{MATHWORKS_DOC_URL}/{MATLAB_BUILTINS['ones']}",
+        ]
+
+
+class TestIdentifiers:
+    """Test class for the identifiers of an Expr."""
+
+    @staticmethod
+    def _identifiers(text: str) -> list[str]:
+        mock_node = Mock()
+        mock_node.text = text.encode("utf-8")
+        return Expr(nodes=[mock_node], encoding="utf-8").identifiers  # type: ignore
+
+    def test_call(self):
+        """Test identifiers of a function call."""
+        assert self._identifiers("zeros(3, n)") == ["zeros", "n"]
+
+    def test_dotted_call(self):
+        """Test that dotted names are kept together."""
+        assert self._identifiers("pkg.sub.func(x)") == ["pkg.sub.func", "x"]
+
+    def test_field_after_indexing(self):
+        """Test that fields after indexing are not identifiers."""
+        assert self._identifiers("s(1).value + t{2}.other") == ["s", "t"]
+
+    def test_char_array_contents_ignored(self):
+        """Test that function-looking words in character arrays are ignored."""
+        assert self._identifiers("disp('zeros(3) it''s')") == ["disp"]
+
+    def test_string_contents_ignored(self):
+        """Test that function-looking words in strings are ignored."""
+        assert self._identifiers('mustBeMember(x, ["ones", "zeros"])') == ["mustBeMember", "x"]
+
+    def test_transpose(self):
+        """Test that transposes are not mistaken for character arrays."""
+        assert self._identifiers("a' * b.' + c(1)' + [d' e']") == ["a", "b", "c", "d", "e"]
+
+    def test_char_array_after_whitespace_in_brackets(self):
+        """Test that a quote following whitespace in brackets starts a character array."""
+        assert self._identifiers("[a 'zeros'] + {b 'ones'} + f(c ')") == ["a", "b", "f", "c"]
+
+    def test_comments_ignored(self):
+        """Test that comments and continuations are ignored."""
+        assert self._identifiers("f(x, ... ones\n y) % zeros") == ["f", "x", "y"]
+
+    def test_numbers_ignored(self):
+        """Test that numeric literals are ignored."""
+        assert self._identifiers("1e-3 * x + 0xFFu8 + 2i - .5") == ["x"]
+
+    def test_keywords_ignored(self):
+        """Test that keywords are not identifiers."""
+        assert self._identifiers("x(end)") == ["x"]
+
+    def test_unique(self):
+        """Test that identifiers are only listed once."""
+        assert self._identifiers("x + x * y") == ["x", "y"]
+
+
+class TestLiteral:
+    """Test class for evaluating literal Expr values."""
+
+    @staticmethod
+    def _literal(text: str):
+        mock_node = Mock()
+        mock_node.text = text.encode("utf-8")
+        return Expr(nodes=[mock_node], encoding="utf-8").literal  # type: ignore
+
+    def test_integer(self):
+        """Test integer literals, including negative ones."""
+        assert self._literal("3") == 3
+        assert self._literal("-3") == -3
+        assert isinstance(self._literal("3"), int)
+
+    def test_float(self):
+        """Test floating point literals."""
+        assert self._literal("2.5") == 2.5
+        assert self._literal("-.5") == -0.5
+        assert self._literal("1e-3") == 0.001
+
+    def test_char_array(self):
+        """Test character array literals with escaped quotes."""
+        assert self._literal("'hello'") == "hello"
+        assert self._literal("'it''s'") == "it's"
+        assert self._literal("''") == ""
+
+    def test_string(self):
+        """Test string literals with escaped quotes."""
+        assert self._literal('"str"') == "str"
+        assert self._literal('"say ""hi"""') == 'say "hi"'
+
+    def test_logical(self):
+        """Test logical literals."""
+        assert self._literal("true") is True
+        assert self._literal("false") is False
+
+    def test_array(self):
+        """Test bracketed array literals."""
+        assert self._literal("[1 2 3]") == [1, 2, 3]
+        assert self._literal("[1, -2, 3.5]") == [1, -2, 3.5]
+        assert self._literal('["a" "b"]') == ["a", "b"]
+        assert self._literal("[1 2; 3 4]") == [[1, 2], [3, 4]]
+
+    def test_empty(self):
+        """Test that the empty array is distinguished from non-literals."""
+        assert self._literal("[]") == []
+        assert self._literal("[ ]") == []
+
+    def test_not_literal(self):
+        """Test that expressions with calls, identifiers or operators are not literals."""
+        assert self._literal("zeros(3)") is None
+        assert self._literal("x") is None
+        assert self._literal("[1 - 2]") is None
+        assert self._literal("[1 x]") is None
+        assert self._literal("'a' + 'b'") is None
+        assert self._literal("['a' 'b']") is None
+
+    def test_string_value(self):
+        """Test that only single character arrays and strings have a string value."""
+        for text, value in [("'it''s'", "it's"), ('"say ""hi"""', 'say "hi"'), ("''", "")]:
+            expr = Expr(nodes=[Mock(text=text.encode("utf-8"))], encoding="utf-8")
+            assert expr.is_string_literal
+            assert expr.string_value == value
+        for text in ("x", '["a" "b"]', "3", "'a' + 'b'"):
+            expr = Expr(nodes=[Mock(text=text.encode("utf-8"))], encoding="utf-8")
+            assert not expr.is_string_literal
+            assert expr.string_value is None
+
+    def test_numeric_value(self):
+        """Test that only single numbers have a numeric value."""
+        for text, value in [("3", 3.0), ("-.5", -0.5), ("1e-3", 0.001)]:
+            expr = Expr(nodes=[Mock(text=text.encode("utf-8"))], encoding="utf-8")
+            assert expr.is_numeric_literal
+            assert expr.numeric_value == value
+        for text in ("true", "'3'", "[1 2]", "pi"):
+            expr = Expr(nodes=[Mock(text=text.encode("utf-8"))], encoding="utf-8")
+            assert not expr.is_numeric_literal
+            assert expr.numeric_value is None
+
+
+class TestValidators:
+    """Test class for parsing validation functions."""
+
+    def test_names(self):
+        """Test validators without arguments."""
+        assert parse_validators("{mustBePositive, mustBeInteger}") == [
+            Validator("mustBePositive"),
+            Validator("mustBeInteger"),
+        ]
+        assert parse_validators("{}") == []
+
+    def test_arguments(self):
+        """Test that the arguments are kept as raw text, including nested commas."""
+        validators = parse_validators('{mustBeMember(x, ["a","b"]), mustBeInRange(x, 0, 10)}')
+
+        assert validators == [
+            Validator("mustBeMember", 'x, ["a","b"]'),
+            Validator("mustBeInRange", "x, 0, 10"),
+        ]
+        assert str(validators[0]) == 'mustBeMember(x, ["a","b"])'
+
+    def test_quotes_and_continuations(self):
+        """Test commas in character arrays and validators spread over several lines."""
+        validators = parse_validators("{mustBeMember(x, {'a,b', 'it''s'}), ...\n mustBeText}")
+
+        assert [validator.name for validator in validators] == ["mustBeMember", "mustBeText"]
+        assert validators[0].arguments == "x, {'a,b', 'it''s'}"
+
+    def test_char_array_after_whitespace(self):
+        """Test that a quote following whitespace in brackets starts a character array."""
+        validators = parse_validators("{mustBeMember(x, [a 'b,c'])}")
+
+        assert [validator.name for validator in validators] == ["mustBeMember"]
+        assert validators[0].arguments == "x, [a 'b,c']"
+
+    def test_malformed(self):
+        """Test that a malformed list degrades to a single raw entry."""
+        for text in ("{mustBePositive, }", "{mustBeMember(x, [1 2)}", "{1 + 2}"):
+            validators = parse_validators(text)
+            assert validators == [Validator(text, raw=True)]
+            assert validators[0].doc == ""
+
+    def test_doc(self):
+        """Test that builtin validators resolve to their documentation."""
+        validators = parse_validators("{mustBePositive, myValidator}")
+
+        assert validators[0].doc == f"{MATHWORKS_DOC_URL}/{MATLAB_BUILTINS['mustBePositive']}"
+        assert validators[1].doc == ""
+
+
+class TestResolve:
+    """Test class for resolving the identifiers of an Expr."""
+
+    @staticmethod
+    def _expr(text: str) -> Expr:
+        mock_node = Mock()
+        mock_node.text = text.encode("utf-8")
+        return Expr(nodes=[mock_node], encoding="utf-8")  # type: ignore
+
+    def test_resolve_without_collection(self):
+        """Test that only builtins are resolved without a paths collection."""
+        references = self._expr("zeros(n)").resolve()
+        assert references == [
+            Reference(
+                "zeros",
+                ReferenceKind.builtin,
+                url=f"{MATHWORKS_DOC_URL}/{MATLAB_BUILTINS['zeros']}",
+            ),
+            Reference("n", ReferenceKind.unknown),
+        ]
+
+    def test_resolve_object_shadows_builtin(self, mock_collection):
+        """Test that collected objects take precedence over builtins."""
+        zeros = Class("zeros", filepath=Path("/path/to/zeros.m"))
+        references = self._expr("zeros(3)").resolve(mock_collection(zeros))
+        assert references == [Reference("zeros", ReferenceKind.object, path="zeros")]
+
+    def test_resolve_dotted_object(self, mock_collection):
+        """Test that dotted names resolve through their leading identifier."""
+        my_enum = Class("MyEnum", filepath=Path("/path/to/MyEnum.m"))
+        references = self._expr("MyEnum.Value").resolve(mock_collection(my_enum))
+        assert references == [Reference("MyEnum.Value", ReferenceKind.object, path="MyEnum")]
+
+    def test_property_default_links(self, mock_collection):
+        """Test building documentation cross-reference links for property defaults."""
+        my_enum = Class("MyEnum", filepath=Path("/path/to/MyEnum.m"))
+        cls = Class("MyClass", filepath=Path("/path/to/MyClass.m"))
+        for name, default in [("Data", "zeros(3)"), ("Mode", "MyEnum.Fast"), ("Tag", "'none'")]:
+            cls.members[name] = Property(name, default=self._expr(default), parent=cls)
+        collection = mock_collection(my_enum, cls)
+
+        links: dict[str, list[str]] = {}
+        for name, prop in cls.properties.items():
+            assert isinstance(prop.default, Expr)
+            links[name] = [
+                reference.url or f"#{reference.path}"
+                for reference in prop.default.resolve(collection)
+                if reference.kind is not ReferenceKind.unknown
+            ]
+
+        assert links == {
+            "Data": [f"{MATHWORKS_DOC_URL}/{MATLAB_BUILTINS['zeros']}"],
+            "Mode": ["#MyEnum"],
+            "Tag": [],
+        }
+
+
+class TestBuiltins:
+    """Test class for the MATLAB builtins database."""
+
+    @pytest.fixture(autouse=True)
+    def restore_builtins(self):
+        """Restore the shipped builtins after each test."""
+        saved = dict(MATLAB_BUILTINS)
+        yield
+        set_builtins(saved)
+
+    def test_shipped_builtins_loaded(self):
+        """Test that the shipped builtins resolve a mathworks.com URL."""
+        mock_node = Mock()
+        mock_node.text = b"zeros"
+
+        expr = Expr(nodes=[mock_node], encoding="utf-8")  # type: ignore
+
+        assert expr.doc == "https://www.mathworks.com/help/matlab/ref/zeros.html"
+
+    def test_get_builtins(self):
+        """Test that get_builtins returns the active database."""
+        assert get_builtins() is MATLAB_BUILTINS
+        assert "zeros" in get_builtins()
+
+    def test_builtins_loaded_lazily(self, monkeypatch, tmp_path):
+        """Test that the environment variable is read on first use, not on import."""
+        monkeypatch.setattr(expressions, "_builtins_loaded", False)
+        monkeypatch.setenv(MATLAB_BUILTINS_ENV, str(tmp_path / "missing.json"))
+
+        with pytest.raises(ValueError, match=MATLAB_BUILTINS_ENV):
+            get_builtins()
+
+        json_path = tmp_path / "builtins.json"
+        json_path.write_text(json.dumps({"myfunc": "ref/myfunc.html"}))
+        monkeypatch.setenv(MATLAB_BUILTINS_ENV, str(json_path))
+
+        assert get_builtins() == {"myfunc": "ref/myfunc.html"}
+
+    def test_set_builtins(self):
+        """Test that set_builtins replaces the database in place."""
+        set_builtins({"myfunc": "ref/myfunc.html"})
+
+        mock_node = Mock()
+        mock_node.text = b"myfunc"
+        expr = Expr(nodes=[mock_node], encoding="utf-8")  # type: ignore
+
+        assert MATLAB_BUILTINS == {"myfunc": "ref/myfunc.html"}
+        assert expr.doc == f"{MATHWORKS_DOC_URL}/ref/myfunc.html"
+
+    def test_set_builtins_invalid(self):
+        """Test that set_builtins rejects non-string entries and keeps the database."""
+        with pytest.raises(ValueError, match="non-string entry"):
+            set_builtins({"zeros": 1})  # type: ignore
+        assert "zeros" in MATLAB_BUILTINS
+
+    def test_load_builtins(self, tmp_path):
+        """Test loading the builtins database from a JSON file."""
+        json_path = tmp_path / "builtins.json"
+        json_path.write_text(json.dumps({"myfunc": "ref/myfunc.html"}))
+
+        load_builtins(json_path)
+
+        assert MATLAB_BUILTINS == {"myfunc": "ref/myfunc.html"}
+
+    def test_load_builtins_invalid(self, tmp_path):
+        """Test that loading a file without a mapping raises."""
+        json_path = tmp_path / "builtins.json"
+        json_path.write_text(json.dumps(["zeros"]))
+
+        with pytest.raises(ValueError, match="must be a mapping"):
+            load_builtins(json_path)
@@ -1,14 +1,20 @@
 """Tests for the objects module."""
 
+import copy
 from pathlib import Path
 
 import pytest
 
-from maxx.enums import AccessKind, ArgumentKind, Kind
+from maxx.enums import AccessKind, ArgumentKind, Kind, TestRole
+from maxx.exceptions import ArgumentOrderError
+from maxx.expressions import Validator
 from maxx.objects import (
+    Alias,
     Argument,
     Arguments,
     Class,
+    ClassFolder,
+    Docstring,
     Enumeration,
     Function,
     Namespace,
@@ -33,6 +39,36 @@ class TestValidatable:
         assert "[optional]" in str(arg)
         assert "y: int = 5" in str(arg)
 
+    def test_declaration(self):
+        """Test the declaration in MATLAB syntax."""
+        arg = Argument(
+            "x",
+            dimensions=[1, None],
+            type="double",
+            validators=[Validator("mustBeNumeric"), Validator("mustBeLessThan", "x, 10")],
+            default="0",
+        )
+        assert arg.declaration == "x (1,:) double {mustBeNumeric, mustBeLessThan(x, 10)} = 0"
+        assert Argument("name", dimensions=[1, 1], type="string").declaration == "name (1,1) string"
+        assert Argument("y").declaration == "y"
+
+    def test_declaration_name_value(self):
+        """Test that name-value arguments are declared with their structure."""
+        arg = Argument("Mode", type="string", default='"fast"', name_value_struct="options")
+        assert arg.declaration == 'options.Mode string = "fast"'
+
+    def test_declaration_raw_validators(self):
+        """Test that a malformed validator list is declared as written."""
+        arg = Argument("x", validators=[Validator("{mustBe(}", raw=True)])
+        assert arg.declaration == "x {mustBe(}"
+
+    def test_is_scalar(self):
+        """Test that only (1,1) dimensions are scalar."""
+        assert Argument(name="x", dimensions=[1, 1]).is_scalar
+        assert not Argument(name="x", dimensions=[1, None]).is_scalar
+        assert not Argument(name="x", dimensions=[3, 1]).is_scalar
+        assert not Argument(name="x").is_scalar
+
 
 class TestArgument:
     """Test class for Argument."""
@@ -62,6 +98,10 @@ class TestArgument:
         arg2 = Argument(name="x", type="double", default="1.0", kind=ArgumentKind.optional)
         assert arg1 == arg2
 
+    def test_eq_different_repeating(self):
+        """Test inequality for a repeating and a non-repeating argument."""
+        assert Argument(name="x", repeating=True) != Argument(name="x")
+
     def test_eq_different_name(self):
         """Test inequality for different names."""
         arg1 = Argument(name="x", type="double", default="1.0")
@@ -79,6 +119,19 @@ class TestArgument:
         arg = Argument(name="x", type="double", default="1.0")
         assert (arg == "not an argument") is False
 
+    def test_lines(self):
+        """Test that arguments have the lines of their declaration, if known."""
+        arg = Argument(name="x", lineno=3, endlineno=4)
+        assert (arg.lineno, arg.endlineno) == (3, 4)
+        assert Argument(name="y").lineno is None
+        assert Property("Width", lineno=5).lineno == 5
+
+    def test_name_interned(self):
+        """Test that equal argument names share a single string."""
+        arg1 = Argument(name="".join(["va", "lue"]))
+        arg2 = Argument(name="".join(["val", "ue"]))
+        assert arg1.name is arg2.name
+
 
 class TestArguments:
     """Test class for Arguments container."""
@@ -254,6 +307,33 @@ class TestObject:
         # Inherits from PathMixin which checks for "+internal" in path
         assert script.is_hidden is False
 
+    def test_name_interned(self):
+        """Test that equal object names share a single string."""
+        func1 = Function(name="".join(["my", "_func"]), filepath=Path("/a/my_func.m"))
+        func2 = Function(name="".join(["my_", "func"]), filepath=Path("/b/my_func.m"))
+        assert func1.name is func2.name
+
+    def test_walk(self):
+        """Test that walk visits an object and its members depth-first."""
+        namespace = Namespace("pkg", filepath=Path("/path/to/+pkg"))
+        cls = Class("Shape", filepath=Path("/path/to/+pkg/Shape.m"), parent=namespace)
+        cls.members["Area"] = Property("Area", parent=cls)
+        cls.members["draw"] = Function("draw", parent=cls)
+        func = Function("helper", filepath=Path("/path/to/+pkg/helper.m"), parent=namespace)
+        namespace.members["Shape"] = cls
+        namespace.members["helper"] = func
+
+        assert [obj.name for obj in namespace.walk()] == ["pkg", "Shape", "Area", "draw", "helper"]
+        assert list(func.walk()) == [func]
+
+    def test_walk_shared_member(self):
+        """Test that members reachable through several parents are visited once."""
+        script = Script(name="my_script", filepath=Path("/path/to/my_script.m"))
+        func = Function(name="func", filepath=Path("/path/to/func.m"))
+        script.members["func"] = func
+        script.members["alias"] = Alias("alias", target=func)
+        assert list(script.walk()) == [script, func]
+
 
 class TestEnumeration:
     """Test class for Enumeration."""
@@ -320,6 +400,89 @@ class TestFunction:
         method.parent = parent_class
         assert method.is_constructor_method is False
 
+    def test_has_early_return_multiple(self):
+        """Test that multiple returns are early returns."""
+        func = Function("f", return_sites=[3, 5], trailing_return=True)
+        assert func.has_early_return is True
+
+    def test_has_early_return_trailing(self):
+        """Test that a single trailing return is not an early return."""
+        func = Function("f", return_sites=[5], trailing_return=True)
+        assert func.has_early_return is False
+
+    def test_has_early_return_nested(self):
+        """Test that a single return that does not end the body is an early return."""
+        func = Function("f", return_sites=[3], trailing_return=False)
+        assert func.has_early_return is True
+
+    def test_modifies_inputs(self):
+        """Test that set methods and functions assigning to input fields modify their inputs."""
+        cls = Class("Shape", filepath=Path("/path/to/Shape.m"))
+        assert Function("Area", parent=cls, setter=True).modifies_inputs is True
+        assert Function("Area", parent=cls, getter=True).modifies_inputs is False
+        assert Function("resize", assigns_input_fields=True).modifies_inputs is True
+        assert Function("area").modifies_inputs is False
+
+    def test_may_throw(self):
+        """Test that only functions calling error outside of a try statement may throw."""
+        assert Function("f", calls_error=True).may_throw is True
+        assert Function("g", calls_error=True, try_catch_count=1).may_throw is False
+        assert Function("h").may_throw is False
+
+    def test_mixes_nargin_with_arguments(self):
+        """Test that only functions using nargin besides an arguments block are recognized."""
+        assert Function("f", has_arguments_block=True, nargin_sites=[3]).mixes_nargin_with_arguments
+        assert not Function("g", has_arguments_block=True).mixes_nargin_with_arguments
+        assert not Function("h", nargin_sites=[3]).mixes_nargin_with_arguments
+
+    def test_has_repeating_args(self):
+        """Test that functions with repeating arguments are recognized."""
+        func = Function("f", arguments=Arguments(Argument("x"), Argument("y", repeating=True)))
+        assert func.has_repeating_args is True
+        assert Function("g", arguments=Arguments(Argument("x"))).has_repeating_args is False
+
+    def test_name_value_args(self):
+        """Test that name-value arguments are listed in order."""
+        text = Argument("text", kind=ArgumentKind.keyword_only, name_value_struct="options")
+        func = Function(
+            "f", arguments=Arguments(Argument("x", kind=ArgumentKind.positional_only), text)
+        )
+        assert func.name_value_args == [text]
+        assert text.is_name_value
+        assert not func.arguments["x"].is_name_value
+        assert Argument("text", kind=ArgumentKind.keyword_only) != text
+
+    def test_validate_argument_order(self):
+        """Test that arguments declared out of order are reported by name and kind."""
+        valid = Function(
+            "f",
+            arguments=Arguments(
+                Argument("x", kind=ArgumentKind.positional_only),
+                Argument("y", kind=ArgumentKind.optional),
+                Argument("z"),
+                Argument("Mode", kind=ArgumentKind.keyword_only),
+            ),
+        )
+        valid.validate_argument_order()
+
+        invalid = Function(
+            "g",
+            arguments=Arguments(
+                Argument("y", kind=ArgumentKind.optional),
+                Argument("x", kind=ArgumentKind.positional_only),
+            ),
+        )
+        with pytest.raises(ArgumentOrderError) as error:
+            invalid.validate_argument_order()
+        assert error.value.argument == "x"
+        assert str(error.value) == (
+            "Argument 'x' is positional-only, expected optional or keyword-only or varargin"
+        )
+
+    def test_has_early_return_none(self):
+        """Test that a function without returns has no early return."""
+        assert Function("f").has_early_return is False
+
 
 class TestProperty:
     """Test class for Property."""
@@ -461,6 +624,13 @@ class TestObjectAdvanced:
         func_no_doc.docstring = None
         assert func_no_doc.has_docstring is False
 
+    def test_h1_line(self):
+        """Test that the H1 line is the first non-empty line of the docstring."""
+        func = Function("scale", docstring=Docstring("\n  SCALE Scale a vector.\n\nDetails."))
+        assert func.h1_line == "SCALE Scale a vector."
+        assert Function("f").h1_line is None
+        assert Function("g", docstring=Docstring("")).h1_line is None
+
 
 class TestClass:
     """Tests for Class-specific functionality."""
@@ -498,6 +668,181 @@ class TestClass:
             # It's acceptable if MRO cannot be computed without a paths_collection
             pass
 
+    def test_all_methods_subclass_wins(self, make_class, mock_collection):
+        """Test that methods defined in the subclass override inherited ones."""
+        base = make_class("Base", ["handle"], ["Base", "run", "stop"], [])
+        child = make_class("Child", ["Base"], ["Child", "run"], [])
+        methods = child.all_methods(mock_collection(base, child))
+        assert set(methods) == {"Child", "run", "stop"}
+        assert methods["run"].parent is child
+        assert methods["stop"].parent is base
+
+    def test_all_properties_records_defining_class(self, make_class, mock_collection):
+        """Test that inherited properties keep track of the class defining them."""
+        base = make_class("Base", [], [], ["Value", "Name"])
+        child = make_class("Child", ["Base"], [], ["Value"])
+        properties = child.all_properties(mock_collection(base, child))
+        assert set(properties) == {"Value", "Name"}
+        assert properties["Value"].parent is child
+        assert properties["Name"].parent is base
+
+    def test_all_methods_unresolvable_base(self, make_class, mock_collection):
+        """Test that builtin or unknown bases are skipped."""
+        child = make_class("Child", ["handle", "matlab.mixin.Copyable"], ["run"], [])
+        methods = child.all_methods(mock_collection(child))
+        assert set(methods) == {"run"}
+
+    def test_all_methods_without_collection(self, make_class):
+        """Test that only declared members are returned without a paths collection."""
+        child = make_class("Child", ["Base"], ["run"], ["Value"])
+        assert set(child.all_methods()) == {"run"}
+        assert set(child.all_properties()) == {"Value"}
+
+    def test_all_methods_diamond(self, make_class, mock_collection):
+        """Test that diamond inheritance terminates and prefers the nearest definition."""
+        top = make_class("Top", [], ["shared", "top"], [])
+        left = make_class("Left", ["Top"], ["shared"], [])
+        right = make_class("Right", ["Top"], ["right"], [])
+        bottom = make_class("Bottom", ["Left", "Right"], [], [])
+        methods = bottom.all_methods(mock_collection(top, left, right, bottom))
+        assert set(methods) == {"shared", "top", "right"}
+        assert methods["shared"].parent is left
+
+    def test_all_methods_cycle(self, make_class, mock_collection):
+        """Test that the bases are skipped when the inheritance is cyclic."""
+        first = make_class("First", ["Second"], ["one"], [])
+        second = make_class("Second", ["First"], ["two"], [])
+        collection = mock_collection(first, second)
+        assert set(first.all_methods(collection)) == {"one"}
+        with pytest.raises(ValueError, match="inheritance cycle"):
+            first.mro(collection)
+
+    def test_mro_class_folder_base(self, make_class, mock_collection):
+        """Test that class folders are followed as bases, with the methods in their folder."""
+        base = make_class("Base", ["matlab.unittest.TestCase"], ["Base"], [])
+        folder = ClassFolder("Base", filepath=Path("/path/to/@Base"), classfile=base)
+        folder.members["run"] = Function("run", parent=folder)
+        child = make_class("Child", ["Base"], [], [])
+        collection = mock_collection(folder, child)
+
+        assert child.mro(collection) == [folder]
+        assert set(child.all_methods(collection)) == {"run"}
+        assert child.is_test_class(collection)
+
+    def test_effective_docstring_inherited(self, make_class, mock_collection):
+        """Test that an undocumented override uses the nearest documented base method."""
+        base = make_class("Base", [], ["run"], [])
+        base.members["run"].docstring = Docstring("Run the task.")
+        middle = make_class("Middle", ["Base"], ["run"], [])
+        child = make_class("Child", ["Middle"], ["Child", "run"], [])
+        collection = mock_collection(base, middle, child)
+
+        run = child.members["run"]
+        assert run.effective_docstring(collection).value == "Run the task."
+        assert run.docstring_inherited_from(collection) == base.path
+
+    def test_effective_docstring_own(self, make_class, mock_collection):
+        """Test that documented methods, constructors and functions keep their docstring."""
+        base = make_class("Base", [], ["Base", "run"], [])
+        base.members["Base"].docstring = Docstring("Create a base.")
+        base.members["run"].docstring = Docstring("Run the task.")
+        child = make_class("Child", ["Base"], ["Child", "run"], [])
+        child.members["run"].docstring = Docstring("Run the child task.")
+        collection = mock_collection(base, child)
+
+        assert child.members["run"].effective_docstring(collection).value == "Run the child task."
+        assert child.members["run"].docstring_inherited_from(collection) is None
+        assert child.members["Child"].effective_docstring(collection) is None
+        assert Function("func").effective_docstring() is None
+
+    def test_effective_docstring_see_parent(self, make_class, mock_collection):
+        """Test that a `See parent` marker forces inheritance and is skipped in bases."""
+        base = make_class("Base", [], ["run"], [])
+        base.members["run"].docstring = Docstring("Run the task.")
+        middle = make_class("Middle", ["Base"], ["run"], [])
+        middle.members["run"].docstring = Docstring("See parent.")
+        child = make_class("Child", ["Middle"], ["run"], [])
+        child.members["run"].docstring = Docstring("Stub.\n\nSee parent")
+        collection = mock_collection(base, middle, child)
+
+        assert child.members["run"].effective_docstring(collection).value == "Run the task."
+        assert middle.members["run"].docstring_inherited_from(collection) == base.path
+
+    def test_effective_docstring_undocumented(self, make_class, mock_collection):
+        """Test that the own docstring is kept when no base documents the method."""
+        base = make_class("Base", ["handle"], ["run"], [])
+        child = make_class("Child", ["Base"], ["run"], [])
+        child.members["run"].docstring = Docstring("See parent")
+        collection = mock_collection(base, child)
+
+        assert child.members["run"].effective_docstring(collection).value == "See parent"
+        assert child.members["run"].docstring_inherited_from(collection) is None
+
+    def test_is_test_class(self, make_class, mock_collection):
+        """Test that classes deriving from a test case class are test classes."""
+        base = make_class("SolverTestBase", ["matlab.unittest.TestCase"], [], [])
+        child = make_class("SolverTest", ["SolverTestBase"], [], [])
+        other = make_class("Solver", ["handle"], [], [])
+        collection = mock_collection(base, child, other)
+
+        assert base.is_test_class()
+        assert child.is_test_class(collection)
+        assert not child.is_test_class(mock_collection(child))
+        assert not other.is_test_class(collection)
+        folder = ClassFolder("SolverTest", filepath=Path("/path/to/@SolverTest"), classfile=child)
+        assert folder.is_test_class(collection)
+
+    def test_function_is_test(self, make_class, mock_collection):
+        """Test that test methods and functions named `test*` are recognized as tests."""
+        base = make_class("SolverTestBase", ["matlab.unittest.TestCase"], ["testSolve"], [])
+        other = make_class("Solver", ["handle"], ["testInput"], [])
+        collection = mock_collection(base, other)
+
+        assert base.members["testSolve"].is_test(paths_collection=collection)
+        assert not other.members["testInput"].is_test(paths_collection=collection)
+        assert Function("run", test_role=TestRole.test).is_test()
+        assert Function("solverTest", is_function_test=True).is_test()
+        assert Function("TestHelper").is_test()
+        assert Function("testHelper").is_test(base)
+        assert not Function("testHelper").is_test(other, collection)
+        assert not Function("helper").is_test(base)
+
+    def test_getter_setter_for(self, make_class):
+        """Test looking up the get/set methods of a property."""
+        cls = make_class("Shape", [], ["area"], ["Area", "Name"])
+        getter = Function("Area", parent=cls, getter=True)
+        setter = Function("Area", parent=cls, setter=True)
+        cls.members["Area"].getter = getter
+        cls.members["Area"].setter = setter
+
+        assert cls.getter_for("Area") is getter
+        assert cls.setter_for("Area") is setter
+        assert cls.getter_for("Name") is None
+        assert cls.setter_for("Name") is None
+        assert cls.getter_for("area") is None
+        assert cls.getter_for("Missing") is None
+
+        folder = ClassFolder("Shape", filepath=Path("/path/to/@Shape"), classfile=cls)
+        assert folder.getter_for("Area") is getter
+        assert ClassFolder("Empty", filepath=Path("/path/to/@Empty")).setter_for("Area") is None
+
+    def test_is_method_public(self):
+        """Test that methods with a restricted access are not public."""
+        cls = Class("Shape", filepath=Path("/path/to/@Shape/Shape.m"))
+        cls.members["area"] = Function("area", parent=cls)
+        cls.members["scale"] = Function("scale", parent=cls, Access=AccessKind.protected)
+        cls.members["Area"] = Property("Area", parent=cls)
+
+        assert cls.is_method_public("area")
+        assert not cls.is_method_public("scale")
+        assert not cls.is_method_public("Area")
+        assert not cls.is_method_public("missing")
+
+        folder = ClassFolder("Shape", filepath=Path("/path/to/@Shape"), classfile=cls)
+        folder.members["draw"] = Function("draw", filepath=Path("/path/to/@Shape/draw.m"))
+        assert not folder.is_method_public("scale")
+        assert folder.is_method_public("draw")
+
     def test_repr_with_parent(self):
         """Test __repr__ with parent."""
         prop = Property(name="myProp", filepath=Path("/path/to/myProp.m"))
@@ -518,6 +863,26 @@ class TestObjectProperties:
         assert isinstance(path, str)
         assert "MyClass" in path
 
+    def test_is_public(self):
+        """Test that an explicit public flag takes precedence over the access of an object."""
+        func = Function("helper", Access=AccessKind.private)
+        assert not func.is_public
+        func.public = True
+        assert func.is_public
+        assert Function("run").is_public
+        assert not Function("run", filepath=Path("/path/to/private/run.m")).is_public
+
+    def test_effective_access(self):
+        """Test that the access of the enclosing block restricts the access of an object."""
+        func = Function("scale", Access=AccessKind.protected)
+        assert func.effective_access() is AccessKind.protected
+        assert func.effective_access(AccessKind.public) is AccessKind.protected
+        assert func.effective_access(AccessKind.private) is AccessKind.private
+        immutable = Function("run", Access=AccessKind.immutable)
+        assert immutable.effective_access() is AccessKind.immutable
+        func.public = False
+        assert func.effective_access() is AccessKind.private
+
     def test_module_raises(self):
         """Test that module property raises ValueError."""
         cls = Class(name="MyClass", filepath=Path("/path/to/MyClass.m"))
@@ -549,6 +914,63 @@ class TestObjectProperties:
             pass
 
 
+class TestCopy:
+    """Test copying objects."""
+
+    @staticmethod
+    def _class() -> Class:
+        """Create a class with a documented method."""
+        cls = Class("Shape", filepath=Path("/path/to/Shape.m"), docstring=Docstring("A shape."))
+        cls.members["area"] = Function(
+            "area",
+            parent=cls,
+            arguments=Arguments(Argument("scale", type="double")),
+            docstring=Docstring("The area."),
+        )
+        return cls
+
+    def test_copy(self):
+        """Test that a shallow copy shares the members of the original."""
+        cls = self._class()
+        copied = copy.copy(cls)
+
+        assert copied is not cls
+        assert copied.members is cls.members
+        assert copied.name == "Shape"
+
+    def test_deepcopy(self):
+        """Test that a deep copy can be modified without affecting the original."""
+        cls = self._class()
+        copied = copy.deepcopy(cls)
+        method = copied.members["area"]
+
+        assert method is not cls.members["area"]
+        assert method.parent is copied
+        assert method.arguments[0].function is method
+        method.docstring.value = "Changed."
+        copied.members.pop("area")
+        assert cls.members["area"].docstring.value == "The area."
+
+    def test_deepcopy_member(self):
+        """Test that a deep copy of a member keeps referring to the original parent."""
+        cls = self._class()
+        method = copy.deepcopy(cls.members["area"])
+        argument = copy.deepcopy(cls.members["area"].arguments[0])
+
+        assert method.parent is cls
+        assert argument.function is cls.members["area"]
+
+    def test_deepcopy_alias(self):
+        """Test that a deep copy of an unresolved alias resolves on its own."""
+        function = Function("func")
+        alias = Alias("func", target=lambda: function)
+        copied = copy.deepcopy(alias)
+
+        assert not copied.resolved
+        assert copied.target is function
+        assert not alias.resolved
+
+
 class TestAlias:
     """Tests for Alias class."""
 
@@ -602,6 +1024,23 @@ class TestEnumerationAdvanced:
         enum.value = "42"  # ty: ignore[invalid-assignment]
         assert str(enum.value) == "42"
 
+    def test_enumeration_class(self):
+        """Test that classes with enumeration members are enumeration classes."""
+        cls = Class("Color", filepath=Path("/path/to/Color.m"))
+        cls.members["Value"] = Property("Value", parent=cls)
+        assert not cls.is_enumeration
+
+        red = Enumeration("Red", parent=cls)
+        green = Enumeration("Green", parent=cls)
+        cls.members["Red"] = red
+        cls.members["Green"] = green
+        assert cls.is_enumeration
+        assert cls.enumeration_values == [red, green]
+
+        folder = ClassFolder("Color", filepath=Path("/path/to/@Color"), classfile=cls)
+        assert folder.is_enumeration
+        assert not ClassFolder("Empty", filepath=Path("/path/to/@Empty")).is_enumeration
+
     def test_enumeration_str_representation(self):
         """Test string representation of enumeration."""
         enum = Enumeration(name="EnumValue", filepath=Path("/path/to/Enum.m"))
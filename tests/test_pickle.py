@@ -0,0 +1,221 @@
+"""Tests for pickling parsed objects and collections."""
+
+import multiprocessing
+import pickle
+from enum import Enum
+from pathlib import Path
+from unittest.mock import Mock
+
+import pytest
+
+from maxx.collection import PathsCollection
+from maxx.enums import AccessKind, ArgumentKind
+from maxx.exceptions import MaltError
+from maxx.expressions import Expr, Validator
+from maxx.objects import (
+    Alias,
+    Argument,
+    Arguments,
+    Class,
+    Docstring,
+    Function,
+    Namespace,
+    Property,
+    Script,
+)
+from maxx.treesitter import FileParser
+
+
+def _parse(path):
+    """Parse a file in a worker process."""
+    return FileParser(path).parse()
+
+
+def _expr(text: str) -> Expr:
+    """Create an expression of a single node with the given text."""
+    return Expr(nodes=[Mock(text=text.encode())], encoding="utf-8")  # type: ignore
+
+
+def _assert_roundtrip(restored, original, path="object", seen=None):
+    """Assert that a restored value equals the original, attribute by attribute.
+
+    Unpicklable attributes such as tree-sitter nodes must be None after the round-trip, and
+    expressions are compared by their text.
+    """
+    seen = set() if seen is None else seen
+    if id(original) in seen:
+        return
+    if isinstance(original, Expr):
+        assert isinstance(restored, Expr), path
+        assert str(restored) == str(original), path
+    elif isinstance(original, (list, tuple)):
+        assert type(restored) is type(original), path
+        assert len(restored) == len(original), path
+        for index, (left, right) in enumerate(zip(restored, original)):
+            _assert_roundtrip(left, right, f"{path}[{index}]", seen)
+    elif isinstance(original, dict):
+        assert restored.keys() == original.keys(), path
+        for key, value in original.items():
+            _assert_roundtrip(restored[key], value, f"{path}[{key!r}]", seen)
+    elif hasattr(original, "__dict__") and not isinstance(original, (type, Enum)):
+        seen.add(id(original))
+        assert type(restored) is type(original), path
+        assert vars(restored).keys() == vars(original).keys(), path
+        for name, value in vars(original).items():
+            if name in getattr(original, "_unpicklable", ()):
+                assert getattr(restored, name) is None, f"{path}.{name}"
+            else:
+                _assert_roundtrip(getattr(restored, name), value, f"{path}.{name}", seen)
+    else:
+        assert restored == original, path
+
+
+class TestPickleRoundtrip:
+    """Test class for round-tripping fully populated objects through pickle."""
+
+    @staticmethod
+    def _function() -> Function:
+        """Create a function with every attribute set."""
+        return Function(
+            "scale",
+            lineno=1,
+            endlineno=12,
+            filepath=Path("/path/to/scale.m"),
+            docstring=Docstring("Scale a vector.", lineno=2),
+            node=Mock(),
+            arguments=Arguments(
+                Argument(
+                    "x",
+                    type=_expr("double"),
+                    dimensions=[1, None],
+                    validators=[Validator("mustBeFinite"), Validator("mustBeLessThan", "10")],
+                    docstring=Docstring("The vector."),
+                    kind=ArgumentKind.positional_only,
+                ),
+                Argument("factor", default=_expr("1"), kind=ArgumentKind.optional),
+                Argument("varargin", kind=ArgumentKind.varargin, repeating=True),
+            ),
+            returns=Arguments(Argument("y", type="double")),
+            Access=AccessKind.protected,
+            Static=True,
+            return_sites=[8, 11],
+            trailing_return=True,
+            error_ids=["scale:badInput"],
+            warning_ids=["scale:slow"],
+            is_function_test=True,
+        )
+
+    def test_function(self):
+        """Test that every attribute of a function and its arguments survives a round-trip."""
+        function = self._function()
+        _assert_roundtrip(pickle.loads(pickle.dumps(function)), function)
+
+    def test_class(self):
+        """Test that a class keeps its properties and methods, and their parents."""
+        cls = Class(
+            "Shape",
+            bases=["handle", "matlab.mixin.Copyable"],
+            filepath=Path("/path/to/Shape.m"),
+            docstring=Docstring("A shape."),
+            Abstract=True,
+        )
+        cls.members["Area"] = Property(
+            "Area",
+            type="double",
+            default=_expr("0"),
+            validators=[Validator("mustBeNonnegative")],
+            docstring=Docstring("The area."),
+            Dependent=True,
+            SetAccess=AccessKind.private,
+            parent=cls,
+        )
+        cls.members["scale"] = self._function()
+        cls.members["scale"].parent = cls
+
+        restored = pickle.loads(pickle.dumps(cls))
+
+        _assert_roundtrip(restored, cls)
+        assert restored.members["Area"].parent is restored
+
+    def test_script(self):
+        """Test that a script survives a round-trip."""
+        script = Script(
+            "setup", filepath=Path("/path/to/setup.m"), docstring=Docstring("Set up the path.")
+        )
+        _assert_roundtrip(pickle.loads(pickle.dumps(script)), script)
+
+    def test_namespace_with_alias(self):
+        """Test that a namespace keeps its aliased members."""
+        namespace = Namespace("pkg", filepath=Path("/path/to/+pkg"))
+        function = self._function()
+        namespace.members["scale"] = Alias("scale", target=function, parent=namespace)
+
+        restored = pickle.loads(pickle.dumps(namespace))
+
+        _assert_roundtrip(restored, namespace)
+        assert restored.members["scale"].target.name == "scale"
+
+
+class TestPickleObjects:
+    """Test class for pickling parsed objects."""
+
+    def test_function_roundtrip(self, test_files_dir):
+        """Test that a parsed function survives a pickle round-trip."""
+        function = FileParser(test_files_dir / "test_function.m").parse()
+        restored = pickle.loads(pickle.dumps(function))
+
+        assert isinstance(restored, Function)
+        assert restored.name == function.name
+        assert restored.node is None
+        assert [arg.name for arg in restored.arguments] == [arg.name for arg in function.arguments]
+        assert str(restored.arguments["text"].default) == '"Test"'
+        assert restored.docstring is not None
+        assert restored.docstring.value == function.docstring.value
+
+    def test_class_roundtrip(self, test_files_dir):
+        """Test that a parsed class keeps its members and their expressions."""
+        cls = FileParser(test_files_dir / "MyClass.m").parse()
+        restored = pickle.loads(pickle.dumps(cls))
+
+        assert isinstance(restored, Class)
+        assert restored.bases == cls.bases
+        assert set(restored.members) == set(cls.members)
+        prop = restored.members["Property1"]
+        assert isinstance(prop, Property)
+        assert prop.parent is restored
+        assert isinstance(prop.default, Expr)
+        assert str(prop.default) == str(cls.members["Property1"].default)
+
+    def test_incompatible_version(self, test_files_dir):
+        """Test that state pickled by an incompatible version raises a MaltError."""
+        function = FileParser(test_files_dir / "test_function.m").parse()
+        state = function.__getstate__()
+        state["__pickle_version__"] = 0
+
+        restored = Function.__new__(Function)
+        with pytest.raises(MaltError, match="state version 0"):
+            restored.__setstate__(state)
+
+    def test_multiprocessing(self, test_files_dir):
+        """Test that parse results can be returned from worker processes."""
+        paths = [test_files_dir / "test_function.m", test_files_dir / "MyClass.m"]
+        with multiprocessing.Pool(2) as pool:
+            results = pool.map(_parse, paths)
+
+        assert [result.name for result in results] == ["test_function", "MyClass"]
+
+
+class TestPickleCollection:
+    """Test class for pickling paths collections."""
+
+    def test_collection_roundtrip(self, test_files_dir):
+        """Test that a collection survives a pickle round-trip."""
+        collection = PathsCollection([test_files_dir], recursive=True)
+        collection["MyClass"]  # Resolve one object before pickling.
+
+        restored = pickle.loads(pickle.dumps(collection))
+
+        assert set(restored.members) == set(collection.members)
+        assert isinstance(restored["MyClass"], Class)
+        assert isinstance(restored["test_function"], Function)
+        assert restored.get_path("MyClass") == collection.get_path("MyClass")
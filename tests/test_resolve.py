@@ -0,0 +1,99 @@
+"""Tests for the resolve module."""
+
+from pathlib import Path
+
+from maxx.collection import PathsCollection
+from maxx.enums import ReferenceKind
+from maxx.expressions import Reference
+from maxx.objects import Argument, Arguments, Class, Function
+from maxx.resolve import NameResolver
+
+
+class TestNameResolver:
+    """Test class for resolving the identifiers used in functions."""
+
+    @staticmethod
+    def _function() -> Function:
+        return Function(
+            "scale",
+            filepath=Path("/path/to/scale.m"),
+            arguments=Arguments(Argument("x"), Argument("max")),
+            returns=Arguments(Argument("y")),
+        )
+
+    def test_variables(self):
+        """Test that arguments and outputs are variables."""
+        resolver = NameResolver(PathsCollection([]))
+        function = self._function()
+
+        assert resolver.resolve("x", function) == [Reference("x", ReferenceKind.variable)]
+        assert resolver.resolve("y", function) == [Reference("y", ReferenceKind.variable)]
+
+    def test_shadowed_builtin(self):
+        """Test that a variable shadows a builtin of the same name."""
+        resolved = NameResolver(PathsCollection([])).resolve("max", self._function())
+
+        assert [name.kind for name in resolved] == [ReferenceKind.variable, ReferenceKind.builtin]
+        assert resolved[1].url.endswith("max.html")
+
+    def test_local_function(self):
+        """Test that methods resolve the local functions of their class file."""
+        helper = Function("helper", filepath=Path("/path/to/Shape.m"))
+        cls = Class("Shape", filepath=Path("/path/to/Shape.m"), local_functions=[helper])
+        method = Function("area", parent=cls)
+
+        resolved = NameResolver(PathsCollection([])).resolve("helper", method)
+
+        assert resolved == [Reference("helper", ReferenceKind.local_function, path="helper")]
+
+    def test_local_function_of_function_file(self):
+        """Test that main functions resolve the local functions of their file."""
+        helper = Function("helper", filepath=Path("/path/to/scale.m"))
+        function = Function(
+            "scale",
+            filepath=Path("/path/to/scale.m"),
+            variables=["total"],
+            local_functions=[helper],
+        )
+        resolver = NameResolver(PathsCollection([]))
+
+        assert resolver.resolve("helper", function) == [
+            Reference("helper", ReferenceKind.local_function, path="helper")
+        ]
+        assert resolver.resolve("total", function) == [Reference("total", ReferenceKind.variable)]
+
+    def test_unknown(self):
+        """Test that unresolvable identifiers give a single unknown candidate."""
+        resolved = NameResolver(PathsCollection([])).resolve("doesNotExist", self._function())
+
+        assert resolved == [Reference("doesNotExist", ReferenceKind.unknown)]
+
+    def test_collection(self, tmp_path):
+        """Test that private functions shadow objects on the search path."""
+        (tmp_path / "lib").mkdir()
+        (tmp_path / "private").mkdir()
+        (tmp_path / "main.m").write_text("function main()\nhelper();\nend\n")
+        (tmp_path / "lib" / "helper.m").write_text("function helper()\nend\n")
+        (tmp_path / "private" / "helper.m").write_text("function helper()\nend\n")
+        collection = PathsCollection([tmp_path, tmp_path / "lib"])
+
+        resolved = NameResolver(collection).resolve("helper", collection["main"])
+
+        assert [name.kind for name in resolved] == [
+            ReferenceKind.private_function,
+            ReferenceKind.object,
+        ]
+        assert [name.path for name in resolved] == ["helper", "helper"]
+
+    def test_assigned_variables(self, tmp_path):
+        """Test that assigned and iterated names in the body are variables."""
+        (tmp_path / "body.m").write_text(
+            "function body()\na = 1;\n[b, c] = deal(a);\nfor k = 1:3\n    disp(k);\nend\nend\n"
+        )
+        collection = PathsCollection([tmp_path])
+        resolver = NameResolver(collection)
+        function = collection["body"]
+
+        for name in ("a", "b", "c", "k"):
+            assert resolver.resolve(name, function)[0].kind is ReferenceKind.variable
+        assert resolver.resolve("body", function)[0].kind is ReferenceKind.object
@@ -0,0 +1,46 @@
+"""Tests for the telemetry module."""
+
+from pathlib import Path
+
+import pytest
+
+from maxx.telemetry import CollectionStats, FileStats
+
+
+class TestCollectionStats:
+    """Test class for CollectionStats."""
+
+    @staticmethod
+    def _stats() -> CollectionStats:
+        """Create statistics for three parsed files."""
+        return CollectionStats(
+            walk_time=0.5,
+            files=[
+                FileStats(Path("a.m"), 100, 0.25),
+                FileStats(Path("b.m"), 2000, 1.0),
+                FileStats(Path("c.m"), 300, 0.125),
+            ],
+        )
+
+    def test_totals(self):
+        """Test the totals over all files."""
+        stats = self._stats()
+        assert stats.files_parsed == 3
+        assert stats.bytes_parsed == 2400
+        assert stats.parse_time == pytest.approx(1.375)
+        assert stats.total_time == pytest.approx(1.875)
+
+    def test_slowest(self):
+        """Test that the slowest files are returned first."""
+        stats = self._stats()
+        assert [file.path.name for file in stats.slowest(2)] == ["b.m", "a.m"]
+        assert len(stats.slowest()) == 3
+
+    def test_empty(self):
+        """Test statistics before anything is parsed."""
+        stats = CollectionStats()
+        assert stats.files_parsed == 0
+        assert stats.bytes_parsed == 0
+        assert stats.total_time == 0.0
+        assert stats.slowest() == []
+        assert stats.total_objects == 0
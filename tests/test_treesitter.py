@@ -1,10 +1,15 @@
 """Tests for the treesitter module."""
 
+import pickle
+from pathlib import Path
+
 import pytest
 
-from maxx.enums import AccessKind, ArgumentKind
+from maxx import treesitter
+from maxx.enums import AccessKind, ArgumentKind, ParseIssueKind, TestRole
+from maxx.expressions import Validator
 from maxx.objects import Class, Enumeration, Function, Property, Script
-from maxx.treesitter import FileParser, _strtobool
+from maxx.treesitter import LANGUAGE, FileParser, ParserPool, _strtobool
 
 
 class MyClassParser:
@@ -123,8 +128,7 @@ class MyClassParser:
         assert input1.name == "input1"
         assert str(input1.type) == "double"
         assert "mustBeNumeric" in str(input1.validators)
-        assert input1.dimensions is not None
-        assert "1" in input1.dimensions and ":" in input1.dimensions  # Check for (1,:) dimension
+        assert input1.dimensions == [1, None]  # Check for (1,:) dimension
 
         # Check input1 docstring
         assert input1.docstring is not None
@@ -166,14 +170,15 @@ class MyClassParser:
         assert str(options_text.type) == "string"
         assert str(options_text.default) == '"Modified"'
         assert options_text.kind == ArgumentKind.keyword_only
+        assert options_text.is_name_value
+        assert options_text.name_value_struct == "options"
+        assert method2.name_value_args == [options_text, options_flag]
 
         # Check options.flag details
         assert str(options_flag.type) == "logical"
         assert str(options_flag.default) == "false"
-        assert options_flag.dimensions is not None
-        assert (
-            "1" in options_flag.dimensions and "1" in options_flag.dimensions
-        )  # Check for (1,1) dimension
+        assert options_flag.dimensions == [1, 1]  # Check for (1,1) dimension
+        assert options_flag.is_scalar
         assert options_flag.kind == ArgumentKind.keyword_only
 
         # Check docstrings
@@ -204,10 +209,7 @@ class MyClassParser:
         assert str(factor_arg.type) == "double"
         assert "mustBePositive" in str(factor_arg.validators)
         assert str(factor_arg.default) == "1"
-        assert factor_arg.dimensions is not None
-        assert (
-            "1" in factor_arg.dimensions and "1" in factor_arg.dimensions
-        )  # Check for (1,1) dimension
+        assert factor_arg.dimensions == [1, 1]  # Check for (1,1) dimension
 
         # Find the options.precision argument
         options_precision = None
@@ -218,8 +220,9 @@ class MyClassParser:
         assert options_precision is not None
         assert str(options_precision.type) == "double"
         assert str(options_precision.default) == "2"
-        assert options_precision.validators is not None
-        assert "mustBeInRange" in str(options_precision.validators)
+        assert options_precision.validators == [
+            Validator("mustBeInRange", "options.precision, 0, 10")
+        ]
         assert options_precision.kind == ArgumentKind.keyword_only
 
         # Check docstrings
@@ -279,16 +282,14 @@ def test_parse_function(test_files_dir):
     input1_arg = arguments.get("input1")
     assert input1_arg is not None
     assert str(input1_arg.type) == "double"
-    assert input1_arg.dimensions is not None
-    assert (
-        "1" in input1_arg.dimensions and ":" in input1_arg.dimensions
-    )  # Check for (1,:) dimension
+    assert input1_arg.dimensions == [1, None]  # Check for (1,:) dimension
+    assert not input1_arg.is_scalar
 
     input2_arg = arguments.get("input2")
     assert input2_arg is not None
     assert str(input2_arg.type) == "double"
-    assert input2_arg.validators is not None
-    assert "mustBePositive" in str(input2_arg.validators)
+    assert [validator.name for validator in input2_arg.validators] == ["mustBePositive"]
+    assert input2_arg.validators[0].arguments is None
     assert str(input2_arg.default) == "1"
 
     text_arg = arguments.get("text")
@@ -424,8 +425,8 @@ def test_parse_getter_setter_class(test_files_dir):
     # Check dependent property
     assert "ComputedValue" in model.members
     computed_prop = model.members["ComputedValue"]
-    # Note: Dependent attribute may not be parsed - check if property exists
-    assert computed_prop is not None
+    assert isinstance(computed_prop, Property)
+    assert computed_prop.Dependent is True
 
     # Check for getter and setter methods - they might be parsed separately
     # or linked to the property
@@ -445,6 +446,270 @@ def test_parse_getter_setter_class(test_files_dir):
     assert internal_prop.Access == AccessKind.private
 
 
+def test_parse_getter_setter_for(test_files_dir):
+    """Test that get/set methods are linked to their property."""
+    model = FileParser(test_files_dir / "GetterSetterClass.m").parse()
+
+    assert isinstance(model, Class)
+    getter = model.getter_for("ComputedValue")
+    setter = model.setter_for("ComputedValue")
+    assert isinstance(getter, Function) and getter.is_getter
+    assert isinstance(setter, Function) and setter.is_setter
+    assert model.getter_for("InternalValue") is None
+
+
+def test_parse_diagnostic_ids(tmp_path):
+    """Test that the identifiers of raised errors and warnings are collected."""
+    filepath = tmp_path / "checked.m"
+    filepath.write_text(
+        "function checked(x)\n"
+        "    if x < 0\n"
+        "        error('myapp:negative', 'Negative input %d', x);\n"
+        "    end\n"
+        '    warning("MATLAB:deprecated", "Deprecated");\n'
+        "    error('Plain message:without identifier');\n"
+        "    warning('off', 'myapp:quiet');\n"
+        "    error('myapp:negative', 'Again');\n"
+        "end\n"
+        "\n"
+        "function helper()\n"
+        "    error('myapp:helper', 'In a local function');\n"
+        "end\n"
+    )
+    model = FileParser(filepath).parse()
+
+    assert isinstance(model, Function)
+    assert model.error_ids == ["myapp:negative"]
+    assert model.warning_ids == ["MATLAB:deprecated"]
+
+
+def test_parse_exception_handling(tmp_path):
+    """Test that try statements, empty catch clauses and error calls are detected."""
+    filepath = tmp_path / "guarded.m"
+    filepath.write_text(
+        "function guarded(x)\n"
+        "    try\n"
+        "        check(x);\n"
+        "    catch ME\n"
+        "        rethrow(ME);\n"
+        "    end\n"
+        "    try\n"
+        "        load(x);\n"
+        "    catch\n"
+        "        % Ignore missing files\n"
+        "    end\n"
+        "end\n"
+        "\n"
+        "function check(x)\n"
+        "    if x < 0\n"
+        "        error('Negative input');\n"
+        "    end\n"
+        "end\n"
+    )
+    model = FileParser(filepath).parse()
+
+    assert isinstance(model, Function)
+    assert model.try_catch_count == 2
+    assert model.has_empty_catch
+    assert not model.calls_error
+    assert not model.may_throw
+
+
+def test_parse_nargin_sites(tmp_path):
+    """Test that uses of nargin and nargout are found next to an arguments block."""
+    filepath = tmp_path / "scale.m"
+    filepath.write_text(
+        "function y = scale(x, factor)\n"
+        "    arguments\n"
+        "        x double\n"
+        "        factor double = 2\n"
+        "    end\n"
+        "    if nargin < 2\n"
+        "        factor = 1;\n"
+        "    end\n"
+        "    y = x * factor * nargout;\n"
+        "end\n"
+    )
+    model = FileParser(filepath).parse()
+
+    assert model.has_arguments_block
+    assert model.nargin_sites == [6, 9]
+    assert model.mixes_nargin_with_arguments
+
+
+def test_parse_assigns_input_fields(tmp_path):
+    """Test that assignments to fields of inputs are detected, but not of outputs or variables."""
+    filepath = tmp_path / "resize.m"
+    filepath.write_text(
+        "function resize(shape, factor)\n"
+        "    scaled = factor * 2;\n"
+        "    [shape.Width, height] = deal(scaled, 1);\n"
+        "end\n"
+    )
+    assert FileParser(filepath).parse().assigns_input_fields
+
+    filepath.write_text(
+        "function out = resize(shape, factor)\n"
+        "    out.Width = shape.Width * factor;\n"
+        "    factor(2) = 1;\n"
+        "end\n"
+    )
+    assert not FileParser(filepath).parse().assigns_input_fields
+
+    filepath.write_text(
+        "function shape = resize(shape, factor)\n"
+        "    shape.Width(1) = shape.Width(1) * factor;\n"
+        "end\n"
+    )
+    assert not FileParser(filepath).parse().assigns_input_fields
+
+
+def test_parse_variables_and_local_functions(tmp_path):
+    """Test that assigned and iterated names, and the local functions of the file, are found."""
+    filepath = tmp_path / "total.m"
+    filepath.write_text(
+        "function out = total(values)\n"
+        "    acc = 0;\n"
+        "    for k = 1:numel(values)\n"
+        "        acc = add(acc, values(k));\n"
+        "    end\n"
+        "    [out, ~] = deal(acc, 1);\n"
+        "end\n"
+        "\n"
+        "function c = add(a, b)\n"
+        "    c = a + b;\n"
+        "end\n"
+    )
+    model = FileParser(filepath).parse()
+
+    assert isinstance(model, Function)
+    assert model.variables == ["acc", "k", "out"]
+    assert [function.name for function in model.local_functions] == ["add"]
+    assert model.local_functions[0].variables == ["c"]
+
+
+def test_parse_repeating_arguments(tmp_path):
+    """Test that arguments of a Repeating block are marked as repeating."""
+    filepath = tmp_path / "plot_pairs.m"
+    filepath.write_text(
+        "function plot_pairs(name, x, y)\n"
+        "    arguments\n"
+        "        name (1,1) string\n"
+        "    end\n"
+        "    arguments (Repeating)\n"
+        "        x (1,:) double\n"
+        "        y (1,:) double\n"
+        "    end\n"
+        "end\n"
+    )
+    model = FileParser(filepath).parse()
+
+    assert isinstance(model, Function)
+    assert model.has_repeating_args
+    assert [argument.repeating for argument in model.arguments] == [False, True, True]
+
+
+def test_parse_argument_and_property_lines(tmp_path):
+    """Test that arguments and properties record the lines of their declarations."""
+    filepath = tmp_path / "Shape.m"
+    filepath.write_text(
+        "classdef Shape\n"
+        "    properties\n"
+        "        Width (1,1) double = 1\n"
+        "    end\n"
+        "    methods\n"
+        "        function obj = Shape(width, options)\n"
+        "            arguments\n"
+        "                width (1,1) double\n"
+        "                options.Color string = \"red\"\n"
+        "            end\n"
+        "        end\n"
+        "    end\n"
+        "end\n"
+    )
+    model = FileParser(filepath).parse()
+
+    assert isinstance(model, Class)
+    width = model.members["Width"]
+    assert (width.lineno, width.endlineno) == (3, 3)
+    constructor = model.members["Shape"]
+    assert [argument.lineno for argument in constructor.arguments] == [8, 9]
+
+
+def test_parse_class_local_functions(tmp_path):
+    """Test that the functions after a classdef block are parsed as local functions."""
+    path = tmp_path / "Shape.m"
+    path.write_text(
+        "classdef Shape\n"
+        "    methods\n"
+        "        function a = area(obj)\n"
+        "            a = helper(obj);\n"
+        "        end\n"
+        "    end\n"
+        "end\n"
+        "\n"
+        "function out = helper(in)\n"
+        "% Compute the area.\n"
+        "out = 0;\n"
+        "end\n"
+    )
+    model = FileParser(path).parse()
+
+    assert isinstance(model, Class)
+    assert [function.name for function in model.local_functions] == ["helper"]
+    helper = model.local_functions[0]
+    assert helper.lineno == 9
+    assert [argument.name for argument in helper.arguments] == ["in"]
+    assert "Compute the area." in helper.docstring.value
+    assert "helper" not in model.members
+
+
+def test_parse_test_class(tmp_path):
+    """Test that test classes and the roles of their methods are recognized."""
+    filepath = tmp_path / "SolverTest.m"
+    filepath.write_text(
+        "classdef SolverTest < matlab.unittest.TestCase\n"
+        "    methods (TestMethodSetup)\n"
+        "        function setup(testCase)\n"
+        "        end\n"
+        "    end\n"
+        "    methods (Test)\n"
+        "        function testSolve(testCase)\n"
+        "        end\n"
+        "    end\n"
+        "    methods\n"
+        "        function helper(testCase)\n"
+        "        end\n"
+        "    end\n"
+        "end\n"
+    )
+    model = FileParser(filepath).parse()
+
+    assert isinstance(model, Class)
+    assert model.is_test_class()
+    assert model.members["setup"].test_role is TestRole.method_setup
+    assert model.members["testSolve"].test_role is TestRole.test
+    assert model.members["helper"].test_role is None
+
+
+def test_parse_function_test(tmp_path, test_files_dir):
+    """Test that function-based test files are recognized."""
+    filepath = tmp_path / "solverTest.m"
+    filepath.write_text(
+        "function tests = solverTest\n"
+        "tests = functiontests(localfunctions);\n"
+        "end\n"
+        "\n"
+        "function testSolve(testCase)\n"
+        "end\n"
+    )
+    model = FileParser(filepath).parse()
+
+    assert isinstance(model, Function)
+    assert model.is_function_test
+    assert not FileParser(test_files_dir / "test_function.m").parse().is_function_test
+
+
 def test_parse_block_comment_function(test_files_dir):
     """Test parsing a function with block comments."""
     function_file = test_files_dir / "block_comment_function.m"
@@ -480,6 +745,23 @@ def test_parse_error_handling(test_files_dir):
         assert e is not None
 
 
+def test_parse_tolerant(test_files_dir):
+    """Test that a valid file is parsed tolerantly without issues."""
+    result = FileParser(test_files_dir / "test_function.m").parse_tolerant()
+
+    assert isinstance(result.object, Function)
+    assert result.errors == []
+
+
+def test_parse_tolerant_malformed(test_files_dir):
+    """Test that syntax errors are collected instead of raised."""
+    result = FileParser(test_files_dir / "malformed.m").parse_tolerant()
+
+    assert result.errors
+    assert all(error.kind is ParseIssueKind.syntax for error in result.errors)
+    assert all(error.lineno is not None for error in result.errors)
+
+
 def test_parse_pragma_comments(test_files_dir):
     """Test parsing a function with pragma comments."""
     pragma_file = test_files_dir / "pragma_function.m"
@@ -511,6 +793,7 @@ def test_parse_multiline_docstring(test_files_dir):
     assert "First line" in model.docstring.value
     assert "Second line" in model.docstring.value
     assert "Third line" in model.docstring.value
+    assert model.h1_line == "First line of docstring"
 
 
 def test_parse_complex_block_comment(test_files_dir):
@@ -535,3 +818,152 @@ def test_parse_complex_block_comment(test_files_dir):
     # Verify returns were parsed
     assert model.returns is not None
     assert len(model.returns) == 1
+
+
+def test_parse_reuse_set_filepath(test_files_dir):
+    """Test reusing a parser for another file."""
+    parser = FileParser(test_files_dir / "test_function.m")
+    function = parser.parse()
+    assert isinstance(function, Function)
+
+    parser.set_filepath(test_files_dir / "my_script.m")
+    script = parser.parse()
+
+    assert isinstance(script, Script)
+    assert script.name == "my_script"
+    assert parser.filepath == test_files_dir / "my_script.m"
+
+
+def test_parse_reuse_set_source(test_files_dir):
+    """Test reusing a parser with in-memory content."""
+    parser = FileParser(test_files_dir / "my_script.m")
+    parser.set_source(
+        b"function out = in_memory(x)\n% In-memory function\nout = x;\nend\n",
+        Path("in_memory.m"),
+    )
+    model = parser.parse()
+
+    assert isinstance(model, Function)
+    assert model.name == "in_memory"
+    assert model.docstring is not None
+    assert "In-memory function" in model.docstring.value
+    assert parser.content.startswith("function out = in_memory(x)")
+
+
+def test_parse_source(tmp_path):
+    """Test parsing in-memory content of a file that does not exist."""
+    parser = FileParser(tmp_path / "in_memory.m", source=b"function in_memory\nend\n")
+    model = parser.parse()
+
+    assert isinstance(model, Function)
+    assert model.name == "in_memory"
+
+
+def test_parse_return_sites(test_files_dir):
+    """Test that return statements are located."""
+    model = FileParser(test_files_dir / "early_return.m").parse()
+
+    assert isinstance(model, Function)
+    assert model.return_sites == [8, 11]
+    assert model.trailing_return is True
+    assert model.has_early_return is True
+
+
+def test_parse_no_return_sites(test_files_dir):
+    """Test a function without return statements."""
+    model = FileParser(test_files_dir / "test_function.m").parse()
+
+    assert isinstance(model, Function)
+    assert model.return_sites == []
+    assert model.has_early_return is False
+
+
+def test_comments(tmp_path):
+    """Test listing the comments of a file with their locations."""
+    path = tmp_path / "commented.m"
+    path.write_text(
+        "function out = commented(x)\n"
+        "% Double the input.\n"
+        "\n"
+        "    % Compute the result\n"
+        "    out = 2 * x;  % trailing\n"
+        "end\n"
+    )
+    comments = FileParser(path).comments()
+
+    assert [comment.line for comment in comments] == [2, 4, 5]
+    assert comments[0].text == "% Double the input."
+    assert comments[1].column == 4
+    assert [comment.is_doc_comment for comment in comments] == [True, False, False]
+
+
+def test_string_literals(tmp_path):
+    """Test listing the string and character array literals of a file."""
+    path = tmp_path / "greet.m"
+    path.write_text(
+        "function greet(name)\n"
+        "% Greet someone.\n"
+        "fprintf('It''s %s\\n', name);\n"
+        'disp("Say ""hi""");\n'
+        "end\n"
+    )
+    literals = FileParser(path).string_literals()
+
+    assert [(literal.value, literal.line, literal.column) for literal in literals] == [
+        ("It's %s\\n", 3, 8),
+        ('Say "hi"', 4, 5),
+    ]
+    assert [literal.is_char_array for literal in literals] == [True, False]
+
+
+def test_parse_with_parser(test_files_dir):
+    """Test parsing several files with a parser borrowed from a pool."""
+    pool = ParserPool()
+    names = []
+    for name in ["test_function.m", "MyClass.m", "test_function.m"]:
+        with pool.parser() as parser:
+            names.append(FileParser.with_parser(parser, test_files_dir / name).parse().name)
+
+    assert names == ["test_function", "MyClass", "test_function"]
+    assert len(pool) == 1
+
+
+def test_parser_pool_nested():
+    """Test that parsers in use are not handed out twice."""
+    pool = ParserPool()
+    with pool.parser() as first, pool.parser() as second:
+        assert first is not second
+    assert len(pool) == 2
+
+    with pool.parser() as parser:
+        assert parser in (first, second)
+
+
+def test_parser_pool_pickle():
+    """Test that a pickled pool is restored without parsers."""
+    pool = ParserPool()
+    with pool.parser():
+        pass
+
+    restored = pickle.loads(pickle.dumps(pool))
+    assert isinstance(restored, ParserPool)
+    assert len(restored) == 0
+
+
+def test_parse_with_language(test_files_dir):
+    """Test parsing with a grammar supplied by the caller."""
+    parser = FileParser(test_files_dir / "test_function.m")
+    assert parser.with_language(LANGUAGE) is parser
+
+    model = parser.parse()
+    assert isinstance(model, Function)
+    assert model.name == "test_function"
+
+
+def test_parse_with_incompatible_language(test_files_dir, monkeypatch):
+    """Test that a grammar for which the queries cannot be compiled is rejected."""
+    monkeypatch.setitem(treesitter._QUERY_SOURCES, "UNKNOWN_QUERY", "(unknown_node) @unknown")
+    parser = FileParser(test_files_dir / "test_function.m")
+
+    with pytest.raises(ValueError, match="UNKNOWN_QUERY"):
+        parser.with_language(LANGUAGE)